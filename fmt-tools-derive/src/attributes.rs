@@ -0,0 +1,113 @@
+use syn::{Attribute, Expr, ExprPath, Path, Result};
+
+/// Attributes that can be applied to a struct, an enum or an enum variant.
+#[derive(Default)]
+pub struct ContainerAttributes {
+    pub separator: Option<Expr>,
+    pub crate_path: Option<Path>,
+}
+
+impl ContainerAttributes {
+    /// Parses container attributes. `sep` is rejected if `allows_separator` is `false`.
+    pub fn parse(attributes: &[Attribute], allows_separator: bool) -> Result<Self> {
+        let mut result = Self::default();
+
+        for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("fmt")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("sep") {
+                    if !allows_separator {
+                        return Err(meta.error("`sep` is only supported by `FmtDisplay`"));
+                    }
+
+                    result.separator = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    result.crate_path = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `fmt` attribute, expected `sep` or `crate`"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Attributes that can be applied to a field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub skip: bool,
+    pub redact: bool,
+    pub with: Option<ExprPath>,
+}
+
+impl FieldAttributes {
+    pub fn parse(attributes: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("fmt")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    result.skip = true;
+                } else if meta.path.is_ident("redact") {
+                    result.redact = true;
+                } else if meta.path.is_ident("with") {
+                    result.with = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported `fmt` attribute, expected `skip`, `redact` or `with`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if let (true, Some(with)) = (result.redact, &result.with) {
+            return Err(syn::Error::new_spanned(
+                with,
+                "`redact` and `with` can not be used together",
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContainerAttributes, FieldAttributes};
+    use syn::{parse_quote, Attribute};
+
+    #[test]
+    fn test_container_attributes_separator() {
+        let attributes: [Attribute; 1] = [parse_quote! { #[fmt(sep = ".")] }];
+
+        assert!(ContainerAttributes::parse(&attributes, true)
+            .unwrap()
+            .separator
+            .is_some());
+
+        assert_eq!(
+            ContainerAttributes::parse(&attributes, false)
+                .err()
+                .unwrap()
+                .to_string(),
+            "`sep` is only supported by `FmtDisplay`"
+        );
+    }
+
+    #[test]
+    fn test_field_attributes_redact_with() {
+        let attributes: [Attribute; 3] = [
+            parse_quote! { #[doc = "comment"] },
+            parse_quote! { #[fmt(redact)] },
+            parse_quote! { #[fmt(with = hex)] },
+        ];
+
+        let error = FieldAttributes::parse(&attributes).err().unwrap();
+
+        assert_eq!(error.to_string(), "`redact` and `with` can not be used together");
+    }
+}
@@ -0,0 +1,103 @@
+use crate::attributes::ContainerAttributes;
+use crate::fields::{self, Field};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, Path, Result};
+
+/// Describes a struct or an enum variant that is being formatted.
+pub struct Shape<'a> {
+    pub name: &'a Ident,
+    pub fields: &'a Fields,
+    pub formatted_fields: Vec<Field>,
+    pub attributes: ContainerAttributes,
+    pub crate_path: &'a Path,
+}
+
+/// Implements `trait_path` for `input`, using `body_fn` to generate the `fmt` body for each shape. `allows_separator`
+/// specifies whether the `sep` attribute is supported.
+pub fn expand(
+    mut input: DeriveInput,
+    trait_path: &TokenStream,
+    allows_separator: bool,
+    body_fn: impl Fn(&Shape) -> TokenStream,
+) -> Result<TokenStream> {
+    let container_attributes = ContainerAttributes::parse(&input.attrs, allows_separator)?;
+    let crate_path = container_attributes
+        .crate_path
+        .clone()
+        .unwrap_or_else(|| parse_quote! { ::fmt_tools });
+
+    let arms = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, formatted_fields) = fields::parse(&data.fields)?;
+
+            let body = body_fn(&Shape {
+                name: &input.ident,
+                fields: &data.fields,
+                formatted_fields,
+                attributes: container_attributes,
+                crate_path: &crate_path,
+            });
+
+            vec![quote! { Self #pattern => #body }]
+        }
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let name = &variant.ident;
+                let (pattern, formatted_fields) = fields::parse(&variant.fields)?;
+                let mut attributes = ContainerAttributes::parse(&variant.attrs, allows_separator)?;
+
+                if let Some(crate_path) = &attributes.crate_path {
+                    return Err(syn::Error::new_spanned(
+                        crate_path,
+                        "`crate` can only be applied to a struct or an enum",
+                    ));
+                }
+
+                if attributes.separator.is_none() {
+                    attributes.separator.clone_from(&container_attributes.separator);
+                }
+
+                let body = body_fn(&Shape {
+                    name,
+                    fields: &variant.fields,
+                    formatted_fields,
+                    attributes,
+                    crate_path: &crate_path,
+                });
+
+                Ok(quote! { Self::#name #pattern => #body })
+            })
+            .collect::<Result<_>>()?,
+        Data::Union(data) => return Err(syn::Error::new(data.union_token.span, "unions are not supported")),
+    };
+
+    let type_parameters = input
+        .generics
+        .type_params()
+        .map(|parameter| parameter.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = input.generics.make_where_clause();
+
+    for type_parameter in type_parameters {
+        where_clause
+            .predicates
+            .push(parse_quote! { #type_parameter: #trait_path });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #trait_path for #name #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                #[allow(unused_variables)]
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
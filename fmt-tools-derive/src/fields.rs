@@ -0,0 +1,68 @@
+use crate::attributes::FieldAttributes;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Fields, Path, Result};
+
+/// A field that is not skipped, along with the binding that refers to it in a match arm.
+pub struct Field {
+    pub name: Option<Ident>,
+    pub binding: Ident,
+    pub attributes: FieldAttributes,
+}
+
+impl Field {
+    /// Returns an expression that formats the field with its own formatting trait, after applying field attributes.
+    /// `crate_path` is the path to the `fmt-tools` crate.
+    pub fn value(&self, crate_path: &Path) -> TokenStream {
+        let binding = &self.binding;
+
+        if self.attributes.redact {
+            quote! { #crate_path::fmt_redacted(#binding) }
+        } else if let Some(with) = &self.attributes.with {
+            quote! { #crate_path::fmt_fn(move |f| #with(#binding, f)) }
+        } else {
+            quote! { #binding }
+        }
+    }
+}
+
+/// Returns the pattern that binds all fields, along with the fields that are not skipped.
+pub fn parse(fields: &Fields) -> Result<(TokenStream, Vec<Field>)> {
+    let mut patterns = Vec::new();
+    let mut result = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        let binding = format_ident!("__fmt_tools_field_{}", i);
+
+        if let Some(name) = &field.ident {
+            patterns.push(if attributes.skip {
+                quote! { #name: _ }
+            } else {
+                quote! { #name: #binding }
+            });
+        } else {
+            patterns.push(if attributes.skip {
+                quote! { _ }
+            } else {
+                quote! { #binding }
+            });
+        }
+
+        if !attributes.skip {
+            result.push(Field {
+                name: field.ident.clone(),
+                binding,
+                attributes,
+            });
+        }
+    }
+
+    let pattern = match fields {
+        Fields::Named(_) => quote! { { #(#patterns,)* } },
+        Fields::Unnamed(_) => quote! { ( #(#patterns,)* ) },
+        Fields::Unit => TokenStream::new(),
+    };
+
+    Ok((pattern, result))
+}
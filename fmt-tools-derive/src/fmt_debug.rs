@@ -0,0 +1,31 @@
+use crate::expand::Shape;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Fields;
+
+pub fn body(shape: &Shape) -> TokenStream {
+    let name = shape.name.to_string();
+
+    match shape.fields {
+        Fields::Named(_) => {
+            let fields = shape.formatted_fields.iter().map(|field| {
+                let field_name = field.name.as_ref().map(ToString::to_string);
+                let value = field.value(shape.crate_path);
+
+                quote! { .field(#field_name, &#value) }
+            });
+
+            quote! { f.debug_struct(#name) #(#fields)* .finish() }
+        }
+        Fields::Unnamed(_) => {
+            let fields = shape.formatted_fields.iter().map(|field| {
+                let value = field.value(shape.crate_path);
+
+                quote! { .field(&#value) }
+            });
+
+            quote! { f.debug_tuple(#name) #(#fields)* .finish() }
+        }
+        Fields::Unit => quote! { f.write_str(#name) },
+    }
+}
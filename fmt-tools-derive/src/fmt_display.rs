@@ -0,0 +1,48 @@
+use crate::expand::Shape;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Fields, Path};
+
+// `fmt_display_join` supports tuples of at most 12 values, so more values are joined in nested groups.
+const MAX_JOIN_VALUES: usize = 12;
+
+fn join(crate_path: &Path, values: &[TokenStream]) -> TokenStream {
+    if values.len() > MAX_JOIN_VALUES {
+        let groups = values
+            .chunks(MAX_JOIN_VALUES)
+            .map(|values| join(crate_path, values))
+            .collect::<Vec<_>>();
+
+        join(crate_path, &groups)
+    } else {
+        quote! {
+            #crate_path::fmt_display_join((#(::core::option::Option::Some(#values),)*), separator)
+        }
+    }
+}
+
+pub fn body(shape: &Shape) -> TokenStream {
+    if matches!(shape.fields, Fields::Unit) {
+        let name = shape.name.to_string();
+
+        return quote! { f.write_str(#name) };
+    }
+
+    let separator = shape
+        .attributes
+        .separator
+        .as_ref()
+        .map_or_else(|| quote! { ", " }, |separator| quote! { #separator });
+    let values = shape
+        .formatted_fields
+        .iter()
+        .map(|field| field.value(shape.crate_path))
+        .collect::<Vec<_>>();
+    let join = join(shape.crate_path, &values);
+
+    quote! {{
+        let separator = &#separator;
+
+        ::core::fmt::Display::fmt(&#join, f)
+    }}
+}
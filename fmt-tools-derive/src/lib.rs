@@ -0,0 +1,116 @@
+#![warn(
+    explicit_outlives_requirements,
+    macro_use_extern_crate,
+    meta_variable_misuse,
+    missing_abi,
+    missing_docs,
+    noop_method_call,
+    single_use_lifetimes,
+    trivial_casts,
+    trivial_numeric_casts,
+    unsafe_code,
+    unsafe_op_in_unsafe_fn,
+    unused_crate_dependencies,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_lifetimes,
+    unused_qualifications,
+    variant_size_differences,
+    clippy::cargo_common_metadata,
+    clippy::clone_on_ref_ptr,
+    clippy::cognitive_complexity,
+    clippy::create_dir,
+    clippy::dbg_macro,
+    clippy::debug_assert_with_mut_call,
+    clippy::empty_line_after_outer_attr,
+    clippy::fallible_impl_from,
+    clippy::filetype_is_file,
+    clippy::float_cmp_const,
+    clippy::get_unwrap,
+    clippy::if_then_some_else_none,
+    clippy::imprecise_flops,
+    clippy::let_underscore_must_use,
+    clippy::lossy_float_literal,
+    clippy::multiple_inherent_impl,
+    clippy::mutex_integer,
+    clippy::nonstandard_macro_braces,
+    clippy::panic_in_result_fn,
+    clippy::path_buf_push_overwrite,
+    clippy::pedantic,
+    clippy::print_stderr,
+    clippy::print_stdout,
+    clippy::rc_buffer,
+    clippy::rc_mutex,
+    clippy::rest_pat_in_fully_bound_structs,
+    clippy::string_lit_as_bytes,
+    clippy::suboptimal_flops,
+    clippy::suspicious_operation_groupings,
+    clippy::todo,
+    clippy::trivial_regex,
+    clippy::unimplemented,
+    clippy::unnecessary_self_imports,
+    clippy::unneeded_field_pattern,
+    clippy::use_debug,
+    clippy::use_self,
+    clippy::useless_let_if_seq,
+    clippy::useless_transmute,
+    clippy::verbose_file_reads,
+    clippy::wildcard_dependencies
+)]
+//! Derive macros for the [`fmt-tools`](https://docs.rs/fmt-tools) crate.
+//!
+//! Use them through the `derive` feature of `fmt-tools`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+#[cfg(test)]
+use fmt_tools as _;
+
+mod attributes;
+mod expand;
+mod fields;
+mod fmt_debug;
+mod fmt_display;
+
+fn derive(
+    input: TokenStream,
+    trait_path: &proc_macro2::TokenStream,
+    allows_separator: bool,
+    body_fn: impl Fn(&expand::Shape) -> proc_macro2::TokenStream,
+) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    expand::expand(input, trait_path, allows_separator, body_fn)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives [`Display`](`core::fmt::Display`) by joining the [`Display`](`core::fmt::Display`) output of fields with a
+/// separator, using [`fmt_display_join`](https://docs.rs/fmt-tools/latest/fmt_tools/fn.fmt_display_join.html).
+///
+/// Unit structs and unit variants are formatted as their names.
+///
+/// Supported attributes:
+///
+/// - `#[fmt(sep = expr)]` on a struct, an enum or an enum variant: specifies the separator, which defaults to `", "`.
+/// - `#[fmt(crate = path)]` on a struct or an enum: specifies the path to the `fmt-tools` crate, which defaults to
+///   `::fmt_tools`. This is needed if `fmt-tools` is renamed or only available through a re-export.
+/// - `#[fmt(skip)]` on a field: the field is not formatted.
+/// - `#[fmt(redact)]` on a field: the field is formatted with
+///   [`fmt_redacted`](https://docs.rs/fmt-tools/latest/fmt_tools/fn.fmt_redacted.html).
+/// - `#[fmt(with = path)]` on a field: the field is formatted with a function that has the signature of
+///   `fn(&T, &mut Formatter) -> fmt::Result`.
+#[proc_macro_derive(FmtDisplay, attributes(fmt))]
+pub fn derive_fmt_display(input: TokenStream) -> TokenStream {
+    derive(input, &quote! { ::core::fmt::Display }, true, fmt_display::body)
+}
+
+/// Derives [`Debug`](`core::fmt::Debug`) like the builtin derive macro does, but supports the `crate` attribute and the
+/// field attributes that [`FmtDisplay`](`macro@FmtDisplay`) supports. The `sep` attribute is rejected, since fields are
+/// separated the way the builtin derive macro does.
+#[proc_macro_derive(FmtDebug, attributes(fmt))]
+pub fn derive_fmt_debug(input: TokenStream) -> TokenStream {
+    derive(input, &quote! { ::core::fmt::Debug }, false, fmt_debug::body)
+}
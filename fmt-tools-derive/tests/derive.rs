@@ -0,0 +1,204 @@
+use fmt_tools::{FmtDebug, FmtDisplay};
+use std::fmt::{self, Display, Formatter};
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn hex(value: &u32, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{value:#x}")
+}
+
+#[test]
+fn test_derive_fmt_display_struct() {
+    #[derive(FmtDisplay)]
+    struct Unit;
+
+    #[derive(FmtDisplay)]
+    #[fmt(sep = ".")]
+    struct Version(u32, u32, u32);
+
+    #[derive(FmtDisplay)]
+    struct User {
+        name: &'static str,
+        #[fmt(skip)]
+        #[allow(dead_code)]
+        id: u32,
+        #[fmt(with = hex)]
+        flags: u32,
+    }
+
+    #[derive(FmtDisplay)]
+    #[fmt(sep = '/')]
+    struct Generic<T> {
+        first: T,
+        second: T,
+    }
+
+    assert_eq!(Unit.to_string(), "Unit");
+    assert_eq!(Version(1, 2, 3).to_string(), "1.2.3");
+    assert_eq!(
+        User {
+            name: "foo",
+            id: 7,
+            flags: 255
+        }
+        .to_string(),
+        "foo, 0xff"
+    );
+    assert_eq!(
+        Generic {
+            first: 'a',
+            second: 'b'
+        }
+        .to_string(),
+        "a/b"
+    );
+}
+
+#[test]
+fn test_derive_redact() {
+    #[derive(FmtDisplay, FmtDebug)]
+    struct Credentials {
+        user: &'static str,
+        #[fmt(redact)]
+        password: &'static str,
+    }
+
+    let credentials = Credentials {
+        user: "foo",
+        password: "bar",
+    };
+
+    #[cfg(not(fmt_tools_disable_redaction))]
+    {
+        assert_eq!(credentials.to_string(), "foo, ***");
+        assert_eq!(
+            format!("{credentials:?}"),
+            "Credentials { user: \"foo\", password: *** }"
+        );
+    }
+
+    #[cfg(fmt_tools_disable_redaction)]
+    {
+        assert_eq!(credentials.to_string(), "foo, bar");
+        assert_eq!(
+            format!("{credentials:?}"),
+            "Credentials { user: \"foo\", password: \"bar\" }"
+        );
+    }
+}
+
+#[test]
+fn test_derive_crate_path() {
+    mod reexport {
+        pub use fmt_tools as inner;
+    }
+
+    #[derive(FmtDisplay, FmtDebug)]
+    #[fmt(crate = reexport::inner)]
+    struct Flags(#[fmt(with = hex)] u32, &'static str);
+
+    assert_eq!(Flags(255, "foo").to_string(), "0xff, foo");
+    assert_eq!(format!("{:?}", Flags(255, "foo")), "Flags(0xff, \"foo\")");
+}
+
+#[test]
+fn test_derive_fmt_display_enum() {
+    #[derive(FmtDisplay)]
+    #[fmt(sep = " ")]
+    #[allow(dead_code)]
+    enum Value {
+        Empty,
+        Single(i32),
+        #[fmt(sep = " - ")]
+        Range {
+            start: i32,
+            end: i32,
+        },
+        Pair(i32, #[fmt(skip)] i32, i32),
+    }
+
+    assert_eq!(Value::Empty.to_string(), "Empty");
+    assert_eq!(Value::Single(1).to_string(), "1");
+    assert_eq!(Value::Range { start: 1, end: 2 }.to_string(), "1 - 2");
+    assert_eq!(Value::Pair(1, 2, 3).to_string(), "1 3");
+}
+
+#[test]
+fn test_derive_fmt_display_many_fields() {
+    #[derive(FmtDisplay)]
+    #[fmt(sep = "")]
+    struct Many(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+    assert_eq!(
+        Many(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3).to_string(),
+        "01234567890123"
+    );
+}
+
+#[test]
+fn test_derive_fmt_debug() {
+    #[derive(FmtDebug)]
+    struct Unit;
+
+    #[derive(FmtDebug)]
+    #[allow(dead_code)]
+    struct Tuple(u32, #[fmt(skip)] u32, #[fmt(with = hex)] u32);
+
+    #[derive(FmtDebug)]
+    struct Named<T> {
+        value: T,
+        #[fmt(skip)]
+        #[allow(dead_code)]
+        skipped: T,
+    }
+
+    #[derive(FmtDebug)]
+    enum Enum {
+        A,
+        B(u32),
+        C { value: u32 },
+    }
+
+    assert_eq!(format!("{:?}", Unit), "Unit");
+    assert_eq!(format!("{:?}", Tuple(1, 2, 3)), "Tuple(1, 0x3)");
+    assert_eq!(
+        format!(
+            "{:?}",
+            Named {
+                value: 'a',
+                skipped: 'b'
+            }
+        ),
+        "Named { value: 'a' }"
+    );
+    assert_eq!(format!("{:?}", Enum::A), "A");
+    assert_eq!(format!("{:?}", Enum::B(1)), "B(1)");
+    assert_eq!(format!("{:?}", Enum::C { value: 1 }), "C { value: 1 }");
+    assert_eq!(format!("{:#?}", Enum::C { value: 1 }), "C {\n    value: 1,\n}");
+}
+
+#[test]
+fn test_derive_display_with_separated_list() {
+    #[derive(FmtDisplay)]
+    #[fmt(sep = "=")]
+    struct Pair(&'static str, u32);
+
+    let fmt = fmt_tools::fmt_separated_display_list(|| [Pair("a", 1), Pair("b", 2)], "&");
+
+    assert_eq!(fmt.to_string(), "a=1&b=2");
+}
+
+#[test]
+fn test_display_trait_is_used() {
+    struct Foo;
+
+    impl Display for Foo {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("foo")
+        }
+    }
+
+    #[derive(FmtDisplay)]
+    struct Wrapper(Foo);
+
+    assert_eq!(Wrapper(Foo).to_string(), "foo");
+}
@@ -0,0 +1,147 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// [`Debug`] or [`Display`] a tuple of arguments with a template function.
+///
+/// Unlike [`FmtFn`](`crate::FmtFn`), the type of this object can be named, so it can be stored in struct fields or
+/// returned from trait methods without boxing.
+pub struct FmtArgs<A> {
+    args: A,
+    template: fn(&A, &mut Formatter) -> fmt::Result,
+}
+
+impl<A> FmtArgs<A> {
+    const fn new(args: A, template: fn(&A, &mut Formatter) -> fmt::Result) -> Self {
+        Self { args, template }
+    }
+}
+
+impl<A> Debug for FmtArgs<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (self.template)(&self.args, f)
+    }
+}
+
+impl<A> Display for FmtArgs<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (self.template)(&self.args, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a tuple of arguments with a template function.
+///
+/// See also [`fmt_args`](`crate::fmt_args!`) for creating the object with a format string.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::FmtArgs;
+///
+/// let fmt: FmtArgs<(u32, char)> = fmt_tools::fmt_args((2, 'A'), |(a, b), f| write!(f, "{a}, {b}"));
+///
+/// assert_eq!(format!("{fmt:?}"), "2, A");
+/// assert_eq!(format!("{fmt}"), "2, A");
+/// ```
+pub const fn fmt_args<A>(args: A, template: fn(&A, &mut Formatter) -> fmt::Result) -> FmtArgs<A> {
+    FmtArgs::new(args, template)
+}
+
+/// Like [`format_args_capture`](`crate::format_args_capture`), but returns an [`FmtArgs`](`crate::FmtArgs`) object
+/// whose type only depends on the types of the arguments, in order.
+///
+/// Since the template can not capture anything, variables can not be captured implicitly by the format string.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::FmtArgs;
+///
+/// enum Error {
+///     NotFound(FmtArgs<(Vec<u32>, char)>),
+/// }
+///
+/// let error = Error::NotFound(fmt_tools::fmt_args!("{:?} not found in {name}", vec![2], name = 'A'));
+///
+/// let Error::NotFound(fmt) = error;
+///
+/// assert_eq!(format!("{fmt:?}"), "[2] not found in A");
+/// assert_eq!(format!("{fmt}"), "[2] not found in A");
+/// ```
+#[macro_export]
+macro_rules! fmt_args {
+    (@bind [$($format:tt)*] [$($values:tt)*] [$($patterns:tt)*] [$($bound:tt)*]) => {
+        $crate::fmt_args(($($values)*), |($($patterns)*), __fmt_tools_formatter| {
+            ::core::fmt::Display::fmt(&::core::format_args!($($format)*, $($bound)*), __fmt_tools_formatter)
+        })
+    };
+    (
+        @bind [$($format:tt)*] [$($values:tt)*] [$($patterns:tt)*] [$($bound:tt)*]
+        $name:ident = $value:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::fmt_args!(
+            @bind
+            [$($format)*]
+            [$($values)* $value,]
+            [$($patterns)* __fmt_tools_value,]
+            [$($bound)* $name = __fmt_tools_value,]
+            $($($rest)*)?
+        )
+    };
+    (
+        @bind [$($format:tt)*] [$($values:tt)*] [$($patterns:tt)*] [$($bound:tt)*]
+        $value:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::fmt_args!(
+            @bind
+            [$($format)*]
+            [$($values)* $value,]
+            [$($patterns)* __fmt_tools_value,]
+            [$($bound)* __fmt_tools_value,]
+            $($($rest)*)?
+        )
+    };
+    ($format:literal $(, $($args:tt)*)?) => {
+        $crate::fmt_args!(@bind [$format] [] [] [] $($($args)*)?)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtArgs;
+    use core::fmt::{self, Display, Formatter};
+
+    #[test]
+    fn test_fmt_args() {
+        let fmt = super::fmt_args(('a', "b"), |(a, b), f| f.write_fmt(format_args!("{a}{b}")));
+
+        assert_eq!(std::format!("{fmt:?}"), "ab");
+        assert_eq!(std::format!("{fmt}"), "ab");
+    }
+
+    #[test]
+    fn test_fmt_args_macro() {
+        struct Foo;
+
+        impl Display for Foo {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("foo")
+            }
+        }
+
+        struct Message<'a> {
+            fmt: FmtArgs<(&'a Foo, u32, char)>,
+        }
+
+        let foo = Foo;
+
+        let message = Message {
+            fmt: crate::fmt_args!("{}, {:>3}, {c:?}", &foo, 7, c = 'x'),
+        };
+
+        let empty: FmtArgs<()> = crate::fmt_args!("empty");
+
+        assert_eq!(std::format!("{:?}", message.fmt), "foo,   7, 'x'");
+        assert_eq!(std::format!("{}", message.fmt), "foo,   7, 'x'");
+        assert_eq!(std::format!("{empty}"), "empty");
+        assert_eq!(std::format!("{}", crate::fmt_args!("{}{}", 1, 2,)), "12");
+    }
+}
@@ -0,0 +1,324 @@
+use core::fmt::{self, Debug, Display, Formatter, Write};
+
+const MIME_LINE_LENGTH: usize = 76;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Specifies the alphabet used by [`FmtBase64`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard alphabet, which uses `+` and `/`.
+    Standard,
+    /// The URL and filename safe alphabet, which uses `-` and `_`.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    const fn symbols(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Self::UrlSafe => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        }
+    }
+}
+
+struct SymbolWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    wrap: bool,
+    length: usize,
+}
+
+impl SymbolWriter<'_, '_> {
+    fn write(&mut self, symbol: u8) -> fmt::Result {
+        if self.wrap && self.length != 0 && self.length.is_multiple_of(MIME_LINE_LENGTH) {
+            self.f.write_str("\r\n")?;
+        }
+
+        self.length += 1;
+
+        self.f.write_char(char::from(symbol))
+    }
+}
+
+/// Encodes `bytes` with `symbols`, each symbol representing `bits_per_symbol` bits. If `padding` is `true`, the output
+/// is padded with `=` to a multiple of `symbols_per_group` symbols. If `wrap` is `true`, a line break is inserted
+/// after every [`MIME_LINE_LENGTH`] characters.
+fn encode(
+    bytes: &[u8],
+    symbols: &[u8],
+    bits_per_symbol: u32,
+    symbols_per_group: usize,
+    padding: bool,
+    wrap: bool,
+    f: &mut Formatter,
+) -> fmt::Result {
+    let mask = (1 << bits_per_symbol) - 1;
+    let mut buffer = 0_u32;
+    let mut buffered_bits = 0;
+    let mut writer = SymbolWriter { f, wrap, length: 0 };
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        buffered_bits += 8;
+
+        while buffered_bits >= bits_per_symbol {
+            buffered_bits -= bits_per_symbol;
+            writer.write(symbols[((buffer >> buffered_bits) & mask) as usize])?;
+        }
+    }
+
+    if buffered_bits != 0 {
+        writer.write(symbols[((buffer << (bits_per_symbol - buffered_bits)) & mask) as usize])?;
+    }
+
+    if padding {
+        while !writer.length.is_multiple_of(symbols_per_group) {
+            writer.write(b'=')?;
+        }
+    }
+
+    Ok(())
+}
+
+/// [`Debug`] or [`Display`] bytes as Base64 text.
+///
+/// If the alternate flag is specified in the format string, a line break (`\r\n`) is inserted after every 76
+/// characters, as required by MIME.
+pub struct FmtBase64<T>
+where
+    T: ?Sized,
+{
+    alphabet: Base64Alphabet,
+    padding: bool,
+    bytes: T,
+}
+
+impl<T> FmtBase64<T> {
+    const fn new(bytes: T, alphabet: Base64Alphabet, padding: bool) -> Self {
+        Self {
+            alphabet,
+            padding,
+            bytes,
+        }
+    }
+}
+
+impl<T> Debug for FmtBase64<T>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let wrap = f.alternate();
+
+        encode(
+            self.bytes.as_ref(),
+            self.alphabet.symbols(),
+            6,
+            4,
+            self.padding,
+            wrap,
+            f,
+        )
+    }
+}
+
+impl<T> Display for FmtBase64<T>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] bytes as Base32 text.
+///
+/// If the alternate flag is specified in the format string, a line break (`\r\n`) is inserted after every 76
+/// characters.
+pub struct FmtBase32<T>
+where
+    T: ?Sized,
+{
+    bytes: T,
+}
+
+impl<T> FmtBase32<T> {
+    const fn new(bytes: T) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<T> Debug for FmtBase32<T>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let wrap = f.alternate();
+
+        encode(self.bytes.as_ref(), BASE32_ALPHABET, 5, 8, true, wrap, f)
+    }
+}
+
+impl<T> Display for FmtBase32<T>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] bytes as padded Base64 text using the standard alphabet.
+///
+/// If the alternate flag is specified in the format string, a line break (`\r\n`) is inserted after every 76
+/// characters.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_base64(b"hello?");
+///
+/// assert_eq!(format!("{fmt:?}"), "aGVsbG8/");
+/// assert_eq!(format!("{fmt}"), "aGVsbG8/");
+/// assert_eq!(format!("{}", fmt_tools::fmt_base64(b"hi")), "aGk=");
+/// ```
+pub const fn fmt_base64<T>(bytes: T) -> FmtBase64<T>
+where
+    T: AsRef<[u8]>,
+{
+    FmtBase64::new(bytes, Base64Alphabet::Standard, true)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] bytes as Base64 text using the specified alphabet, with or without
+/// padding.
+///
+/// If the alternate flag is specified in the format string, a line break (`\r\n`) is inserted after every 76
+/// characters.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::Base64Alphabet;
+///
+/// let fmt = fmt_tools::fmt_base64_with(b"hello?", Base64Alphabet::UrlSafe, false);
+///
+/// assert_eq!(format!("{fmt:?}"), "aGVsbG8_");
+/// assert_eq!(format!("{fmt}"), "aGVsbG8_");
+/// assert_eq!(format!("{}", fmt_tools::fmt_base64_with(b"hi", Base64Alphabet::UrlSafe, false)), "aGk");
+/// ```
+pub const fn fmt_base64_with<T>(bytes: T, alphabet: Base64Alphabet, padding: bool) -> FmtBase64<T>
+where
+    T: AsRef<[u8]>,
+{
+    FmtBase64::new(bytes, alphabet, padding)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] bytes as padded Base32 text.
+///
+/// If the alternate flag is specified in the format string, a line break (`\r\n`) is inserted after every 76
+/// characters.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_base32(b"foobar");
+///
+/// assert_eq!(format!("{fmt:?}"), "MZXW6YTBOI======");
+/// assert_eq!(format!("{fmt}"), "MZXW6YTBOI======");
+/// ```
+pub const fn fmt_base32<T>(bytes: T) -> FmtBase32<T>
+where
+    T: AsRef<[u8]>,
+{
+    FmtBase32::new(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Base64Alphabet, FmtBase32, FmtBase64};
+
+    #[test]
+    fn test_fmt_base64() {
+        let test_cases = [
+            ("", "", ""),
+            ("f", "Zg==", "Zg"),
+            ("fo", "Zm8=", "Zm8"),
+            ("foo", "Zm9v", "Zm9v"),
+            ("foob", "Zm9vYg==", "Zm9vYg"),
+            ("fooba", "Zm9vYmE=", "Zm9vYmE"),
+            ("foobar", "Zm9vYmFy", "Zm9vYmFy"),
+        ];
+
+        for (bytes, expected, expected_unpadded) in test_cases {
+            let fmt = super::fmt_base64(bytes);
+            let unsized_fmt: &FmtBase64<[u8]> = &super::fmt_base64(*b"foo");
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), "Zm9v");
+            assert_eq!(std::format!("{unsized_fmt}"), "Zm9v");
+
+            assert_eq!(
+                std::format!("{}", super::fmt_base64_with(bytes, Base64Alphabet::Standard, false)),
+                expected_unpadded
+            );
+        }
+    }
+
+    #[test]
+    fn test_fmt_base64_alphabets() {
+        let bytes = [0xfb, 0xff, 0xbf];
+
+        assert_eq!(
+            std::format!("{}", super::fmt_base64_with(bytes, Base64Alphabet::Standard, true)),
+            "+/+/"
+        );
+        assert_eq!(
+            std::format!("{}", super::fmt_base64_with(bytes, Base64Alphabet::UrlSafe, true)),
+            "-_-_"
+        );
+    }
+
+    #[test]
+    fn test_fmt_base32() {
+        let test_cases = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+
+        for (bytes, expected) in test_cases {
+            let fmt = super::fmt_base32(bytes);
+            let unsized_fmt: &FmtBase32<[u8]> = &super::fmt_base32(*b"foo");
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), "MZXW6===");
+            assert_eq!(std::format!("{unsized_fmt}"), "MZXW6===");
+        }
+    }
+
+    #[test]
+    fn test_fmt_base64_alternate() {
+        let bytes = [0; 114];
+        let line = "A".repeat(76);
+        let fmt = super::fmt_base64(bytes);
+
+        assert_eq!(std::format!("{fmt:#}"), [line.as_str(), line.as_str()].join("\r\n"));
+        assert_eq!(std::format!("{:#}", super::fmt_base64([0; 57])), line);
+        assert_eq!(std::format!("{:#}", super::fmt_base64([0; 58])), line + "\r\nAA==");
+        assert_eq!(std::format!("{fmt}"), "A".repeat(152));
+    }
+
+    #[test]
+    fn test_fmt_base32_alternate() {
+        let fmt = super::fmt_base32([0; 50]);
+        let expected = ["A".repeat(76), "A".repeat(4)].join("\r\n");
+
+        assert_eq!(std::format!("{fmt:#}"), expected);
+    }
+}
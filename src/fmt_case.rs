@@ -0,0 +1,413 @@
+use crate::utilities;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+
+/// Specifies the case style used by [`FmtCase`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Case {
+    /// `snake_case`.
+    Snake,
+    /// `kebab-case`.
+    Kebab,
+    /// `camelCase`.
+    Camel,
+    /// `PascalCase`.
+    Pascal,
+    /// `SCREAMING_SNAKE_CASE`.
+    ScreamingSnake,
+    /// `Title Case`.
+    Title,
+}
+
+impl Case {
+    const fn delimiter(self) -> Option<char> {
+        match self {
+            Self::Snake | Self::ScreamingSnake => Some('_'),
+            Self::Kebab => Some('-'),
+            Self::Camel | Self::Pascal => None,
+            Self::Title => Some(' '),
+        }
+    }
+
+    const fn is_upper(self, word_index: usize, char_index: usize) -> bool {
+        match self {
+            Self::Snake | Self::Kebab => false,
+            Self::Camel => word_index != 0 && char_index == 0,
+            Self::Pascal | Self::Title => char_index == 0,
+            Self::ScreamingSnake => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CharKind {
+    Upper,
+    Lower,
+    Other,
+}
+
+struct CaseWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    case: Case,
+    words: usize,
+    word_length: usize,
+    previous: CharKind,
+    pending_upper: Option<char>,
+}
+
+impl<'a, 'b> CaseWriter<'a, 'b> {
+    fn new(f: &'a mut Formatter<'b>, case: Case) -> Self {
+        Self {
+            f,
+            case,
+            words: 0,
+            word_length: 0,
+            previous: CharKind::Other,
+            pending_upper: None,
+        }
+    }
+
+    fn emit(&mut self, c: char) -> fmt::Result {
+        if self.word_length == 0 {
+            if self.words != 0 {
+                if let Some(delimiter) = self.case.delimiter() {
+                    self.f.write_char(delimiter)?;
+                }
+            }
+
+            self.words += 1;
+        }
+
+        if self.case.is_upper(self.words - 1, self.word_length) {
+            c.to_uppercase().try_for_each(|c| self.f.write_char(c))?;
+        } else {
+            c.to_lowercase().try_for_each(|c| self.f.write_char(c))?;
+        }
+
+        self.word_length += 1;
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> fmt::Result {
+        self.pending_upper.take().map_or(Ok(()), |c| self.emit(c))
+    }
+
+    fn push(&mut self, c: char) -> fmt::Result {
+        if !c.is_alphanumeric() {
+            self.flush()?;
+            self.word_length = 0;
+
+            return Ok(());
+        }
+
+        let in_word = self.word_length != 0;
+
+        if c.is_uppercase() {
+            if in_word && self.previous == CharKind::Upper {
+                // An uppercase letter after another uppercase letter could be either the last letter of an acronym or
+                // the first letter of the next word, which is unknown until the next character is seen.
+                if let Some(pending) = self.pending_upper.replace(c) {
+                    self.emit(pending)?;
+                }
+
+                return Ok(());
+            }
+
+            if in_word {
+                self.word_length = 0;
+            }
+
+            self.previous = CharKind::Upper;
+        } else {
+            let is_lower = c.is_lowercase();
+
+            if let Some(pending) = self.pending_upper.take() {
+                if is_lower {
+                    self.word_length = 0;
+                }
+
+                self.emit(pending)?;
+            }
+
+            self.previous = if is_lower { CharKind::Lower } else { CharKind::Other };
+        }
+
+        self.emit(c)
+    }
+}
+
+/// [`Debug`] or [`Display`] a [`Display`] object with its output converted to the specified [`Case`].
+///
+/// Words are split at non-alphanumeric characters, at lowercase to uppercase transitions, and before the last letter
+/// of a sequence of uppercase letters that is followed by a lowercase letter, so `HTTPServer` becomes `http_server` in
+/// snake case.
+pub struct FmtCase<T>
+where
+    T: ?Sized,
+{
+    case: Case,
+    value: T,
+}
+
+impl<T> FmtCase<T> {
+    const fn new(value: T, case: Case) -> Self {
+        Self { case, value }
+    }
+}
+
+impl<T> Debug for FmtCase<T>
+where
+    T: Display + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut writer = CaseWriter::new(f, self.case);
+
+        utilities::try_for_each_char(format_args!("{}", &&self.value), |c| writer.push(c))?;
+
+        writer.flush()
+    }
+}
+
+impl<T> Display for FmtCase<T>
+where
+    T: Display + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object with its output converted to the specified
+/// [`Case`].
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::Case;
+///
+/// let fmt = fmt_tools::fmt_case("XMLHttpRequest", Case::Kebab);
+///
+/// assert_eq!(format!("{fmt:?}"), "xml-http-request");
+/// assert_eq!(format!("{fmt}"), "xml-http-request");
+/// ```
+pub const fn fmt_case<T>(value: T, case: Case) -> FmtCase<T>
+where
+    T: Display,
+{
+    FmtCase::new(value, case)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object in `snake_case`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_snake_case("FooBar");
+///
+/// assert_eq!(format!("{fmt:?}"), "foo_bar");
+/// assert_eq!(format!("{fmt}"), "foo_bar");
+/// ```
+pub const fn fmt_snake_case<T>(value: T) -> FmtCase<T>
+where
+    T: Display,
+{
+    FmtCase::new(value, Case::Snake)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object in `kebab-case`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_kebab_case("FooBar");
+///
+/// assert_eq!(format!("{fmt:?}"), "foo-bar");
+/// assert_eq!(format!("{fmt}"), "foo-bar");
+/// ```
+pub const fn fmt_kebab_case<T>(value: T) -> FmtCase<T>
+where
+    T: Display,
+{
+    FmtCase::new(value, Case::Kebab)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object in `camelCase`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_camel_case("foo_bar");
+///
+/// assert_eq!(format!("{fmt:?}"), "fooBar");
+/// assert_eq!(format!("{fmt}"), "fooBar");
+/// ```
+pub const fn fmt_camel_case<T>(value: T) -> FmtCase<T>
+where
+    T: Display,
+{
+    FmtCase::new(value, Case::Camel)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object in `PascalCase`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_pascal_case("foo_bar");
+///
+/// assert_eq!(format!("{fmt:?}"), "FooBar");
+/// assert_eq!(format!("{fmt}"), "FooBar");
+/// ```
+pub const fn fmt_pascal_case<T>(value: T) -> FmtCase<T>
+where
+    T: Display,
+{
+    FmtCase::new(value, Case::Pascal)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object in `SCREAMING_SNAKE_CASE`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_screaming_snake_case("FooBar");
+///
+/// assert_eq!(format!("{fmt:?}"), "FOO_BAR");
+/// assert_eq!(format!("{fmt}"), "FOO_BAR");
+/// ```
+pub const fn fmt_screaming_snake_case<T>(value: T) -> FmtCase<T>
+where
+    T: Display,
+{
+    FmtCase::new(value, Case::ScreamingSnake)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object in `Title Case`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_title_case("foo_bar");
+///
+/// assert_eq!(format!("{fmt:?}"), "Foo Bar");
+/// assert_eq!(format!("{fmt}"), "Foo Bar");
+/// ```
+pub const fn fmt_title_case<T>(value: T) -> FmtCase<T>
+where
+    T: Display,
+{
+    FmtCase::new(value, Case::Title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Case, FmtCase};
+    use core::fmt::Display;
+
+    #[test]
+    fn test_fmt_case() {
+        let test_cases = [
+            ("", ["", "", "", "", "", ""]),
+            ("foo", ["foo", "foo", "foo", "Foo", "FOO", "Foo"]),
+            (
+                "FooBar",
+                ["foo_bar", "foo-bar", "fooBar", "FooBar", "FOO_BAR", "Foo Bar"],
+            ),
+            (
+                "fooBar",
+                ["foo_bar", "foo-bar", "fooBar", "FooBar", "FOO_BAR", "Foo Bar"],
+            ),
+            (
+                "FOO_BAR",
+                ["foo_bar", "foo-bar", "fooBar", "FooBar", "FOO_BAR", "Foo Bar"],
+            ),
+            (
+                "  foo--bar  ",
+                ["foo_bar", "foo-bar", "fooBar", "FooBar", "FOO_BAR", "Foo Bar"],
+            ),
+            (
+                "HTTPServer",
+                [
+                    "http_server",
+                    "http-server",
+                    "httpServer",
+                    "HttpServer",
+                    "HTTP_SERVER",
+                    "Http Server",
+                ],
+            ),
+            (
+                "ServerHTTP",
+                [
+                    "server_http",
+                    "server-http",
+                    "serverHttp",
+                    "ServerHttp",
+                    "SERVER_HTTP",
+                    "Server Http",
+                ],
+            ),
+            (
+                "IPv4Address",
+                [
+                    "i_pv4_address",
+                    "i-pv4-address",
+                    "iPv4Address",
+                    "IPv4Address",
+                    "I_PV4_ADDRESS",
+                    "I Pv4 Address",
+                ],
+            ),
+            (
+                "v2Beta",
+                ["v2_beta", "v2-beta", "v2Beta", "V2Beta", "V2_BETA", "V2 Beta"],
+            ),
+            (
+                "ABC2DEF",
+                ["abc2_def", "abc2-def", "abc2Def", "Abc2Def", "ABC2_DEF", "Abc2 Def"],
+            ),
+            ("A", ["a", "a", "a", "A", "A", "A"]),
+            ("AB", ["ab", "ab", "ab", "Ab", "AB", "Ab"]),
+            ("ABc", ["a_bc", "a-bc", "aBc", "ABc", "A_BC", "A Bc"]),
+            ("Straße", ["straße", "straße", "straße", "Straße", "STRASSE", "Straße"]),
+        ];
+
+        let cases = [
+            Case::Snake,
+            Case::Kebab,
+            Case::Camel,
+            Case::Pascal,
+            Case::ScreamingSnake,
+            Case::Title,
+        ];
+
+        for (value, expected_values) in test_cases {
+            for (case, expected) in cases.into_iter().zip(expected_values) {
+                let fmt = super::fmt_case(value, case);
+                let unsized_fmt: &FmtCase<dyn Display> = &fmt;
+
+                assert_eq!(std::format!("{fmt:?}"), expected);
+                assert_eq!(std::format!("{fmt}"), expected);
+                assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+                assert_eq!(std::format!("{unsized_fmt}"), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fmt_case_functions() {
+        assert_eq!(std::format!("{}", super::fmt_snake_case("FooBar")), "foo_bar");
+        assert_eq!(std::format!("{}", super::fmt_kebab_case("FooBar")), "foo-bar");
+        assert_eq!(std::format!("{}", super::fmt_camel_case("FooBar")), "fooBar");
+        assert_eq!(std::format!("{}", super::fmt_pascal_case("FooBar")), "FooBar");
+        assert_eq!(std::format!("{}", super::fmt_screaming_snake_case("FooBar")), "FOO_BAR");
+        assert_eq!(std::format!("{}", super::fmt_title_case("FooBar")), "Foo Bar");
+    }
+
+    #[test]
+    fn test_fmt_case_with_separated_list() {
+        let fmt = crate::fmt_separated_display_list(|| ["FooBar", "BazQux"].map(super::fmt_snake_case), ", ");
+
+        assert_eq!(std::format!("{fmt}"), "foo_bar, baz_qux");
+    }
+}
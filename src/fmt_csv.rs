@@ -0,0 +1,264 @@
+use crate::fmt_separated_list::{self, Inner};
+use crate::utilities;
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+const DEFAULT_DELIMITER: char = ',';
+
+fn write_field(args: Arguments, delimiter: char, f: &mut Formatter) -> fmt::Result {
+    let mut needs_quoting = false;
+
+    utilities::try_for_each_char(args, |c| {
+        needs_quoting |= c == delimiter || matches!(c, '"' | '\r' | '\n');
+
+        Ok(())
+    })?;
+
+    if needs_quoting {
+        f.write_char('"')?;
+
+        utilities::try_for_each_char(args, |c| if c == '"' { f.write_str("\"\"") } else { f.write_char(c) })?;
+
+        f.write_char('"')
+    } else {
+        utilities::try_for_each_char(args, |c| f.write_char(c))
+    }
+}
+
+fn write_row<I>(cells: I, delimiter: char, f: &mut Formatter) -> fmt::Result
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    fmt_separated_list::fmt_separated(
+        cells,
+        f,
+        |cell, f| write_field(format_args!("{cell}"), delimiter, f),
+        |f| f.write_char(delimiter),
+    )
+}
+
+/// [`Debug`] or [`Display`] a list of [`Display`] objects as a CSV record.
+pub struct FmtCsvRow<F>
+where
+    F: ?Sized,
+{
+    inner: Inner<F, char>,
+}
+
+impl<F> FmtCsvRow<F> {
+    const fn new(values_fn: F, delimiter: char) -> Self {
+        Self {
+            inner: Inner::new(values_fn, delimiter),
+        }
+    }
+}
+
+impl<F, I> Debug for FmtCsvRow<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let delimiter = *self.inner.separator();
+
+        self.inner.fmt_with(
+            f,
+            |cell, f| write_field(format_args!("{cell}"), delimiter, f),
+            |&delimiter, f| f.write_char(delimiter),
+        )
+    }
+}
+
+impl<F, I> Display for FmtCsvRow<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] a list of rows as a CSV document, where each row is a list of [`Display`] objects.
+pub struct FmtCsv<F>
+where
+    F: ?Sized,
+{
+    delimiter: char,
+    rows_fn: F,
+}
+
+impl<F> FmtCsv<F> {
+    const fn new(rows_fn: F, delimiter: char) -> Self {
+        Self { delimiter, rows_fn }
+    }
+}
+
+impl<F, I> Debug for FmtCsv<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (self.rows_fn)().into_iter().try_for_each(|row| {
+            write_row(row, self.delimiter, f)?;
+
+            f.write_str("\r\n")
+        })
+    }
+}
+
+impl<F, I> Display for FmtCsv<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of [`Display`] objects as a comma separated CSV record.
+/// Fields containing the delimiter, `"`, `\r` or `\n` are quoted as described in
+/// [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_csv_row(|| ["abc", "d,e", "say \"hi\""]);
+///
+/// assert_eq!(format!("{fmt:?}"), r#"abc,"d,e","say ""hi""""#);
+/// assert_eq!(format!("{fmt}"), r#"abc,"d,e","say ""hi""""#);
+/// ```
+pub const fn fmt_csv_row<F, I>(values_fn: F) -> FmtCsvRow<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    I::Item: Display,
+{
+    FmtCsvRow::new(values_fn, DEFAULT_DELIMITER)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of [`Display`] objects as a CSV record with the specified
+/// delimiter, like `'\t'` or `';'`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_csv_row_with(|| ["a b", "c\td", "e,f"], '\t');
+///
+/// assert_eq!(format!("{fmt:?}"), "a b\t\"c\td\"\te,f");
+/// assert_eq!(format!("{fmt}"), "a b\t\"c\td\"\te,f");
+/// ```
+pub const fn fmt_csv_row_with<F, I>(values_fn: F, delimiter: char) -> FmtCsvRow<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    I::Item: Display,
+{
+    FmtCsvRow::new(values_fn, delimiter)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of rows as a comma separated CSV document, where each row is
+/// a list of [`Display`] objects. Each record is terminated by `\r\n`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_csv(|| [["name", "score"], ["Alice", "1.5"], ["Bob, Jr.", "2"]]);
+///
+/// assert_eq!(format!("{fmt:?}"), "name,score\r\nAlice,1.5\r\n\"Bob, Jr.\",2\r\n");
+/// assert_eq!(format!("{fmt}"), "name,score\r\nAlice,1.5\r\n\"Bob, Jr.\",2\r\n");
+/// ```
+pub const fn fmt_csv<F, I>(rows_fn: F) -> FmtCsv<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Display,
+{
+    FmtCsv::new(rows_fn, DEFAULT_DELIMITER)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of rows as a CSV document with the specified delimiter, where
+/// each row is a list of [`Display`] objects. Each record is terminated by `\r\n`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_csv_with(|| [["a", "b;c"], ["1", "2"]], ';');
+///
+/// assert_eq!(format!("{fmt:?}"), "a;\"b;c\"\r\n1;2\r\n");
+/// assert_eq!(format!("{fmt}"), "a;\"b;c\"\r\n1;2\r\n");
+/// ```
+pub const fn fmt_csv_with<F, I>(rows_fn: F, delimiter: char) -> FmtCsv<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Display,
+{
+    FmtCsv::new(rows_fn, delimiter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FmtCsv, FmtCsvRow};
+
+    #[test]
+    fn test_fmt_csv_row() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[&str], ',', ""),
+            (&[""], ',', ""),
+            (&["", ""], ',', ","),
+            (&["a", "b"], ',', "a,b"),
+            (&["a,b", "c"], ',', "\"a,b\",c"),
+            (&["a,b", "c"], ';', "a,b;c"),
+            (&["a;b", "c"], ';', "\"a;b\";c"),
+            (&["a\tb", "c"], '\t', "\"a\tb\"\tc"),
+            (&["\"quoted\""], ',', "\"\"\"quoted\"\"\""),
+            (&["line 1\nline 2", "a\rb"], ',', "\"line 1\nline 2\",\"a\rb\""),
+        ];
+
+        for (values, delimiter, expected) in test_cases {
+            let fmt = super::fmt_csv_row_with(|| values, delimiter);
+            let unsized_fmt: &FmtCsvRow<dyn Fn() -> _> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+
+        assert_eq!(std::format!("{}", super::fmt_csv_row(|| [1, 2, 3])), "1,2,3");
+    }
+
+    #[test]
+    fn test_fmt_csv() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[&[&str]], ',', ""),
+            (&[&[]], ',', "\r\n"),
+            (&[&["a", "b"], &["c", "d"]], ',', "a,b\r\nc,d\r\n"),
+            (&[&["a,b"], &["c;d"]], ',', "\"a,b\"\r\nc;d\r\n"),
+            (&[&["a,b"], &["c;d"]], ';', "a,b\r\n\"c;d\"\r\n"),
+        ];
+
+        for (rows, delimiter, expected) in test_cases {
+            let fmt = super::fmt_csv_with(|| rows.iter().copied(), delimiter);
+            let unsized_fmt: &FmtCsv<dyn Fn() -> _> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+}
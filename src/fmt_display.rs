@@ -1,3 +1,4 @@
+use crate::node::{Node, NodeKind, Scalar};
 use core::fmt::{self, Debug, Display, Formatter};
 
 /// [`Debug`] or [`Display`] a value based on its [`Display`] implementation.
@@ -32,6 +33,29 @@ where
     }
 }
 
+impl<T> Node for FmtDisplay<T>
+where
+    T: Display,
+{
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::Scalar(Scalar::String(&self.value))
+    }
+}
+
+/// Serializes the value as a string, using its [`Display`] implementation.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for FmtDisplay<T>
+where
+    T: Display + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&self.value)
+    }
+}
+
 /// Creates an object that [`Debug`] or [`Display`] a value based on its [`Display`] implementation.
 ///
 /// Example:
@@ -82,4 +106,10 @@ mod tests {
         assert_eq!(std::format!("{unsized_fmt:?}"), "foo");
         assert_eq!(std::format!("{unsized_fmt}"), "foo");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_fmt_display() {
+        assert_eq!(serde_json::to_string(&super::fmt_display(42)).unwrap(), r#""42""#);
+    }
 }
@@ -0,0 +1,187 @@
+use crate::fmt_separated_display_list;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter;
+
+/// [`Debug`] or [`Display`] an error along with its chain of [`source`](`Error::source`)s.
+pub struct FmtErrorChain<'a> {
+    error: &'a dyn Error,
+    max_depth: usize,
+}
+
+impl<'a> FmtErrorChain<'a> {
+    const fn new(error: &'a dyn Error, max_depth: usize) -> Self {
+        Self { error, max_depth }
+    }
+
+    fn errors(&self) -> impl Iterator<Item = &'a dyn Error> {
+        iter::successors(Some(self.error), |&error| error.source()).take(self.max_depth)
+    }
+}
+
+impl Debug for FmtErrorChain<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            let mut errors = self.errors();
+
+            if let Some(error) = errors.next() {
+                Display::fmt(error, f)?;
+
+                let mut causes = errors.enumerate().peekable();
+
+                if causes.peek().is_some() {
+                    f.write_str("\n\nCaused by:")?;
+
+                    for (i, cause) in causes {
+                        f.write_fmt(format_args!("\n    {i}: {cause}"))?;
+                    }
+                }
+            }
+
+            Ok(())
+        } else {
+            Display::fmt(&fmt_separated_display_list(|| self.errors(), ": "), f)
+        }
+    }
+}
+
+impl Display for FmtErrorChain<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] an error along with its chain of [`source`](`Error::source`)s.
+///
+/// Errors are separated by `": "` in normal mode. In alternate mode, causes are listed on separate lines after a
+/// `Caused by:` header.
+///
+/// Example:
+///
+/// ```rust
+/// use std::error::Error;
+/// use std::fmt::{self, Display, Formatter};
+///
+/// #[derive(Debug)]
+/// struct Foo(Option<Box<Foo>>, &'static str);
+///
+/// impl Display for Foo {
+///     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+///         f.write_str(self.1)
+///     }
+/// }
+///
+/// impl Error for Foo {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         self.0.as_deref().map(|source| source as _)
+///     }
+/// }
+///
+/// let error = Foo(Some(Box::new(Foo(Some(Box::new(Foo(None, "root cause"))), "cause"))), "top");
+/// let fmt = fmt_tools::fmt_error_chain(&error);
+///
+/// assert_eq!(format!("{fmt}"), "top: cause: root cause");
+/// assert_eq!(format!("{fmt:#}"), "top\n\nCaused by:\n    0: cause\n    1: root cause");
+/// ```
+pub const fn fmt_error_chain(error: &dyn Error) -> FmtErrorChain<'_> {
+    FmtErrorChain::new(error, usize::MAX)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] an error along with its chain of [`source`](`Error::source`)s, but
+/// only shows at most `max_depth` errors in the chain.
+///
+/// Example:
+///
+/// ```rust
+/// use std::error::Error;
+/// use std::fmt::{self, Display, Formatter};
+///
+/// #[derive(Debug)]
+/// struct Foo(Option<Box<Foo>>, &'static str);
+///
+/// impl Display for Foo {
+///     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+///         f.write_str(self.1)
+///     }
+/// }
+///
+/// impl Error for Foo {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         self.0.as_deref().map(|source| source as _)
+///     }
+/// }
+///
+/// let error = Foo(Some(Box::new(Foo(Some(Box::new(Foo(None, "root cause"))), "cause"))), "top");
+/// let fmt = fmt_tools::fmt_error_chain_with_max_depth(&error, 2);
+///
+/// assert_eq!(format!("{fmt}"), "top: cause");
+/// assert_eq!(format!("{fmt:#}"), "top\n\nCaused by:\n    0: cause");
+/// ```
+pub const fn fmt_error_chain_with_max_depth(error: &dyn Error, max_depth: usize) -> FmtErrorChain<'_> {
+    FmtErrorChain::new(error, max_depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::error::Error;
+    use core::fmt::{self, Display, Formatter};
+
+    #[derive(Debug)]
+    struct Foo(Option<&'static Self>, &'static str);
+
+    impl Display for Foo {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str(self.1)
+        }
+    }
+
+    impl Error for Foo {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.0.map(|source| -> &(dyn Error + 'static) { source })
+        }
+    }
+
+    static ROOT: Foo = Foo(None, "root");
+    static MIDDLE: Foo = Foo(Some(&ROOT), "middle");
+    static TOP: Foo = Foo(Some(&MIDDLE), "top");
+
+    #[test]
+    fn test_fmt_error_chain() {
+        let test_cases = [
+            (&ROOT, "root", "root"),
+            (&MIDDLE, "middle: root", "middle\n\nCaused by:\n    0: root"),
+            (
+                &TOP,
+                "top: middle: root",
+                "top\n\nCaused by:\n    0: middle\n    1: root",
+            ),
+        ];
+
+        for (error, expected, expected_alternate) in test_cases {
+            let fmt = super::fmt_error_chain(error);
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{fmt:#?}"), expected_alternate);
+            assert_eq!(std::format!("{fmt:#}"), expected_alternate);
+        }
+    }
+
+    #[test]
+    fn test_fmt_error_chain_with_max_depth() {
+        let test_cases = [
+            (0, "", ""),
+            (1, "top", "top"),
+            (2, "top: middle", "top\n\nCaused by:\n    0: middle"),
+            (3, "top: middle: root", "top\n\nCaused by:\n    0: middle\n    1: root"),
+            (4, "top: middle: root", "top\n\nCaused by:\n    0: middle\n    1: root"),
+        ];
+
+        for (max_depth, expected, expected_alternate) in test_cases {
+            let fmt = super::fmt_error_chain_with_max_depth(&TOP, max_depth);
+
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{fmt:#}"), expected_alternate);
+        }
+    }
+}
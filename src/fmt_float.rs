@@ -0,0 +1,381 @@
+use crate::utilities::{self, StackBuffer};
+use core::fmt::{self, Alignment, Debug, Display, Formatter, Write};
+
+/// The maximum number of significant digits, larger values are clamped to it.
+const MAX_DIGITS: usize = 40;
+
+/// Enough for the longest output, which is [`MAX_DIGITS`] digits with a decimal point and an exponent.
+const BUFFER_SIZE: usize = 64;
+
+/// SI prefixes from 10<sup>-30</sup> to 10<sup>30</sup>.
+const SI_PREFIXES: [&str; 21] = [
+    "q", "r", "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q",
+];
+
+/// Specifies how [`FmtFloat`] writes a floating point value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatStyle {
+    /// Rounds to the specified number of significant digits, keeping trailing zeros. Positional notation is used if
+    /// the decimal exponent is at least -5 and less than the number of digits, scientific notation is used otherwise.
+    Significant(usize),
+    /// Rounds to the specified number of significant digits, and writes the value with an exponent that is a multiple
+    /// of 3, like `4.7e3`. The exponent is omitted if it is zero.
+    Engineering(usize),
+    /// Like [`Engineering`](`Self::Engineering`), but replaces the exponent with an SI prefix, like `4.7k` or `220n`.
+    /// An exponent is written instead if there is no prefix for it.
+    Si(usize),
+    /// Writes the shortest digits that round-trip to the same value. Positional notation is used if the decimal exponent
+    /// is at least -5 and less than 17, scientific notation is used otherwise.
+    Shortest,
+}
+
+/// The decimal digits of a non-negative finite value, with the decimal exponent of the first digit.
+struct Decimal {
+    digits: [u8; MAX_DIGITS],
+    count: usize,
+    exponent: i32,
+}
+
+impl Decimal {
+    /// Computes the digits with [`LowerExp`](`fmt::LowerExp`), rounding to `significant` digits if specified.
+    fn new(value: f64, significant: Option<usize>) -> Result<Self, fmt::Error> {
+        let mut buffer = StackBuffer::<BUFFER_SIZE>::new();
+
+        match significant {
+            None => buffer.write_fmt(format_args!("{value:e}"))?,
+            Some(significant) => buffer.write_fmt(format_args!("{value:.*e}", significant - 1))?,
+        }
+
+        let (mantissa, exponent) = buffer.as_str().split_once('e').ok_or(fmt::Error)?;
+        let mut digits = [0; MAX_DIGITS];
+        let mut count = 0;
+
+        for digit in mantissa.bytes().filter(u8::is_ascii_digit) {
+            *digits.get_mut(count).ok_or(fmt::Error)? = digit;
+            count += 1;
+        }
+
+        Ok(Self {
+            digits,
+            count,
+            exponent: exponent.parse().map_err(|_| fmt::Error)?,
+        })
+    }
+
+    fn digits(&self) -> &str {
+        core::str::from_utf8(&self.digits[..self.count]).unwrap_or_default()
+    }
+
+    /// Writes the digits with `integer_digits` digits before the decimal point, adding zeros as needed.
+    fn write_positional(&self, integer_digits: i32, writer: &mut impl Write) -> fmt::Result {
+        let digits = self.digits();
+
+        match usize::try_from(integer_digits) {
+            Ok(integer_digits) if integer_digits > 0 => {
+                if let Some((integer, fraction)) = digits.split_at_checked(integer_digits) {
+                    writer.write_str(integer)?;
+
+                    if !fraction.is_empty() {
+                        writer.write_char('.')?;
+                        writer.write_str(fraction)?;
+                    }
+
+                    Ok(())
+                } else {
+                    writer.write_str(digits)?;
+
+                    (digits.len()..integer_digits).try_for_each(|_| writer.write_char('0'))
+                }
+            }
+            _ => {
+                writer.write_str("0.")?;
+
+                (integer_digits..0).try_for_each(|_| writer.write_char('0'))?;
+
+                writer.write_str(digits)
+            }
+        }
+    }
+
+    /// Writes the value in positional notation if the exponent is in `positional_exponents`, or in scientific notation
+    /// otherwise.
+    fn write(&self, positional_exponents: impl FnOnce(i32) -> bool, writer: &mut impl Write) -> fmt::Result {
+        if positional_exponents(self.exponent) {
+            self.write_positional(self.exponent + 1, writer)
+        } else {
+            self.write_positional(1, writer)?;
+
+            writer.write_fmt(format_args!("e{}", self.exponent))
+        }
+    }
+
+    /// Writes the value with an exponent that is a multiple of 3, passing the exponent to `exponent_writer`.
+    fn write_engineering<W>(
+        &self,
+        writer: &mut W,
+        exponent_writer: impl FnOnce(i32, &mut W) -> fmt::Result,
+    ) -> fmt::Result
+    where
+        W: Write,
+    {
+        let offset = self.exponent.rem_euclid(3);
+
+        self.write_positional(offset + 1, writer)?;
+
+        exponent_writer(self.exponent - offset, writer)
+    }
+}
+
+fn write_exponent(exponent: i32, writer: &mut impl Write) -> fmt::Result {
+    if exponent == 0 {
+        Ok(())
+    } else {
+        writer.write_fmt(format_args!("e{exponent}"))
+    }
+}
+
+/// Writes `NaN` padded like the standard library does, which never writes a sign for it.
+fn pad_nan(f: &mut Formatter) -> fmt::Result {
+    const NAN: &str = "NaN";
+
+    let padding = f.width().unwrap_or(0).saturating_sub(NAN.len());
+
+    let (fill, before) = if f.sign_aware_zero_pad() {
+        ('0', padding)
+    } else {
+        let before = match f.align() {
+            Some(Alignment::Left) => 0,
+            Some(Alignment::Center) => padding / 2,
+            Some(Alignment::Right) | None => padding,
+        };
+
+        (f.fill(), before)
+    };
+
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    f.write_str(NAN)?;
+    (before..padding).try_for_each(|_| f.write_char(fill))
+}
+
+/// [`Debug`] or [`Display`] a floating point value in a specified [`FloatStyle`].
+///
+/// The width, fill, alignment, sign and zero-padding flags of the format string are honored.
+pub struct FmtFloat {
+    style: FloatStyle,
+    value: f64,
+}
+
+impl FmtFloat {
+    const fn new(value: f64, style: FloatStyle) -> Self {
+        Self { style, value }
+    }
+
+    fn write_magnitude(&self, writer: &mut impl Write) -> fmt::Result {
+        let value = self.value.abs();
+
+        if value.is_infinite() {
+            return writer.write_str("inf");
+        }
+
+        match self.style {
+            FloatStyle::Significant(digits) => {
+                let digits = digits.clamp(1, MAX_DIGITS);
+
+                Decimal::new(value, Some(digits))?.write(
+                    |exponent| exponent >= -5 && usize::try_from(exponent).map_or(true, |exponent| exponent < digits),
+                    writer,
+                )
+            }
+            FloatStyle::Engineering(digits) => {
+                Decimal::new(value, Some(digits.clamp(1, MAX_DIGITS)))?.write_engineering(writer, write_exponent)
+            }
+            FloatStyle::Si(digits) => {
+                Decimal::new(value, Some(digits.clamp(1, MAX_DIGITS)))?.write_engineering(writer, |exponent, writer| {
+                    match usize::try_from(exponent / 3 + 10)
+                        .ok()
+                        .and_then(|index| SI_PREFIXES.get(index))
+                    {
+                        Some(prefix) => writer.write_str(prefix),
+                        None => write_exponent(exponent, writer),
+                    }
+                })
+            }
+            FloatStyle::Shortest => Decimal::new(value, None)?.write(|exponent| (-5..17).contains(&exponent), writer),
+        }
+    }
+}
+
+impl Debug for FmtFloat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.value.is_nan() {
+            return pad_nan(f);
+        }
+
+        let mut buffer = StackBuffer::<BUFFER_SIZE>::new();
+
+        self.write_magnitude(&mut buffer)?;
+
+        f.pad_integral(self.value.is_sign_positive(), "", buffer.as_str())
+    }
+}
+
+impl Display for FmtFloat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a floating point value in a specified [`FloatStyle`].
+///
+/// The width, fill, alignment, sign and zero-padding flags of the format string are honored.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::FloatStyle;
+///
+/// assert_eq!(fmt_tools::fmt_float(0.012345, FloatStyle::Significant(3)).to_string(), "0.0123");
+/// assert_eq!(fmt_tools::fmt_float(47000.0, FloatStyle::Engineering(2)).to_string(), "47e3");
+/// assert_eq!(fmt_tools::fmt_float(4700.0, FloatStyle::Si(2)).to_string(), "4.7k");
+/// assert_eq!(fmt_tools::fmt_float(2.2e-7, FloatStyle::Si(2)).to_string(), "220n");
+/// assert_eq!(fmt_tools::fmt_float(0.1, FloatStyle::Shortest).to_string(), "0.1");
+///
+/// let fmt = fmt_tools::fmt_float(-4.7e-3, FloatStyle::Si(2));
+///
+/// assert_eq!(format!("{fmt:?}"), "-4.7m");
+/// assert_eq!(format!("{fmt:>8}"), "   -4.7m");
+/// assert_eq!(format!("{:+08}", fmt_tools::fmt_float(4.7, FloatStyle::Si(2))), "+00004.7");
+/// ```
+#[must_use]
+pub const fn fmt_float(value: f64, style: FloatStyle) -> FmtFloat {
+    FmtFloat::new(value, style)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] an `f32` value in a specified [`FloatStyle`]. Digits are computed at
+/// the precision of `f32`, so [`FloatStyle::Shortest`] writes the shortest digits that round-trip to the same `f32`.
+///
+/// The width, fill, alignment, sign and zero-padding flags of the format string are honored.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::FloatStyle;
+///
+/// assert_eq!(fmt_tools::fmt_float_f32(0.1, FloatStyle::Shortest).to_string(), "0.1");
+/// assert_eq!(fmt_tools::fmt_float_f32(4700.0, FloatStyle::Si(2)).to_string(), "4.7k");
+/// ```
+#[must_use]
+pub fn fmt_float_f32(value: f32, style: FloatStyle) -> FmtFloat {
+    FmtFloat::new(utilities::f32_to_shortest_f64(value), style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FloatStyle;
+
+    #[test]
+    fn test_fmt_float() {
+        let test_cases = [
+            (0.0, FloatStyle::Significant(3), "0.00"),
+            (1.0, FloatStyle::Significant(1), "1"),
+            (1.5, FloatStyle::Significant(3), "1.50"),
+            (123.456, FloatStyle::Significant(4), "123.5"),
+            (123_456.0, FloatStyle::Significant(6), "123456"),
+            (123_456.0, FloatStyle::Significant(3), "1.23e5"),
+            (9.999, FloatStyle::Significant(2), "10"),
+            (0.000_012_345, FloatStyle::Significant(2), "0.000012"),
+            (0.000_001_234_5, FloatStyle::Significant(2), "1.2e-6"),
+            (1.0, FloatStyle::Significant(0), "1"),
+            (0.0, FloatStyle::Engineering(2), "0.0"),
+            (4.7, FloatStyle::Engineering(2), "4.7"),
+            (4700.0, FloatStyle::Engineering(2), "4.7e3"),
+            (470_000.0, FloatStyle::Engineering(1), "500e3"),
+            (0.000_22, FloatStyle::Engineering(2), "220e-6"),
+            (999_999.0, FloatStyle::Engineering(3), "1.00e6"),
+            (1e-300, FloatStyle::Engineering(1), "1e-300"),
+            (4700.0, FloatStyle::Si(2), "4.7k"),
+            (2.2e-7, FloatStyle::Si(2), "220n"),
+            (1e-6, FloatStyle::Si(1), "1µ"),
+            (123.0, FloatStyle::Si(5), "123.00"),
+            (1e30, FloatStyle::Si(1), "1Q"),
+            (1e33, FloatStyle::Si(1), "1e33"),
+            (0.0, FloatStyle::Shortest, "0"),
+            (1.0, FloatStyle::Shortest, "1"),
+            (0.1, FloatStyle::Shortest, "0.1"),
+            (1234.5, FloatStyle::Shortest, "1234.5"),
+            (1e16, FloatStyle::Shortest, "10000000000000000"),
+            (1e17, FloatStyle::Shortest, "1e17"),
+            (0.000_01, FloatStyle::Shortest, "0.00001"),
+            (0.000_001, FloatStyle::Shortest, "1e-6"),
+            (f64::MIN_POSITIVE, FloatStyle::Shortest, "2.2250738585072014e-308"),
+            (f64::NAN, FloatStyle::Shortest, "NaN"),
+            (f64::INFINITY, FloatStyle::Si(3), "inf"),
+        ];
+
+        for (value, style, expected) in test_cases {
+            let fmt = super::fmt_float(value, style);
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+
+            if !value.is_nan() {
+                assert_eq!(
+                    std::format!("{}", super::fmt_float(-value, style)),
+                    std::format!("-{expected}")
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fmt_float_padding() {
+        let fmt = super::fmt_float(4700.0, FloatStyle::Si(2));
+        let negative = super::fmt_float(-0.5, FloatStyle::Significant(2));
+
+        assert_eq!(std::format!("{fmt:8}"), "    4.7k");
+        assert_eq!(std::format!("{fmt:<8}|"), "4.7k    |");
+        assert_eq!(std::format!("{fmt:*^8}"), "**4.7k**");
+        assert_eq!(std::format!("{fmt:+}"), "+4.7k");
+        assert_eq!(std::format!("{fmt:+08}"), "+0004.7k");
+        assert_eq!(std::format!("{negative:08}"), "-0000.50");
+        assert_eq!(
+            std::format!("{}", super::fmt_float(1.0, FloatStyle::Significant(100))).len(),
+            41
+        );
+    }
+
+    #[test]
+    fn test_fmt_float_nan_padding() {
+        for nan in [f64::NAN, -f64::NAN] {
+            let fmt = super::fmt_float(nan, FloatStyle::Shortest);
+
+            for (actual, expected) in [
+                (std::format!("{fmt:+}"), std::format!("{nan:+}")),
+                (std::format!("{fmt:+08}"), std::format!("{nan:+08}")),
+                (std::format!("{fmt:6}"), std::format!("{nan:6}")),
+                (std::format!("{fmt:*<6}"), std::format!("{nan:*<6}")),
+                (std::format!("{fmt:*^8}"), std::format!("{nan:*^8}")),
+            ] {
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fmt_float_f32() {
+        let test_cases = [
+            (0.1, FloatStyle::Shortest, "0.1"),
+            (16_777_216.0, FloatStyle::Shortest, "16777216"),
+            (f32::MAX, FloatStyle::Shortest, "3.4028235e38"),
+            (f32::MIN_POSITIVE, FloatStyle::Shortest, "1.1754944e-38"),
+            (0.3, FloatStyle::Significant(3), "0.300"),
+            (4700.0, FloatStyle::Si(2), "4.7k"),
+            (f32::NAN, FloatStyle::Shortest, "NaN"),
+            (f32::NEG_INFINITY, FloatStyle::Shortest, "-inf"),
+        ];
+
+        for (value, style, expected) in test_cases {
+            assert_eq!(std::format!("{}", super::fmt_float_f32(value, style)), expected);
+        }
+    }
+}
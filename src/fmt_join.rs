@@ -0,0 +1,330 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+struct Joiner<'a, 'b, S>
+where
+    S: ?Sized,
+{
+    f: &'a mut Formatter<'b>,
+    separator: &'a S,
+    is_first: bool,
+}
+
+impl<'a, 'b, S> Joiner<'a, 'b, S>
+where
+    S: ?Sized,
+{
+    fn new(f: &'a mut Formatter<'b>, separator: &'a S) -> Self {
+        Self {
+            f,
+            separator,
+            is_first: true,
+        }
+    }
+
+    fn entry<T>(
+        &mut self,
+        value: Option<&T>,
+        value_writer: impl FnOnce(&T, &mut Formatter) -> fmt::Result,
+        separator_writer: impl FnOnce(&S, &mut Formatter) -> fmt::Result,
+    ) -> fmt::Result {
+        if let Some(value) = value {
+            if self.is_first {
+                self.is_first = false;
+            } else {
+                separator_writer(self.separator, self.f)?;
+            }
+
+            value_writer(value, self.f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A tuple of optional [`Display`] values that can be joined by [`FmtDisplayJoin`].
+///
+/// This trait is implemented for tuples of up to 12 [`Option`]s, `None` values are skipped.
+pub trait DisplayJoin {
+    /// Writes present values of the tuple, separated by `separator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the formatter fails.
+    fn fmt_joined<S>(&self, separator: &S, f: &mut Formatter) -> fmt::Result
+    where
+        S: Display + ?Sized;
+}
+
+/// A tuple of optional [`Debug`] values that can be joined by [`FmtDebugJoin`].
+///
+/// This trait is implemented for tuples of up to 12 [`Option`]s, `None` values are skipped.
+pub trait DebugJoin {
+    /// Writes present values of the tuple, separated by `separator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the formatter fails.
+    fn fmt_joined<S>(&self, separator: &S, f: &mut Formatter) -> fmt::Result
+    where
+        S: Debug + ?Sized;
+}
+
+macro_rules! impl_join {
+    ($($name:ident)*) => {
+        impl<$($name,)*> DisplayJoin for ($(Option<$name>,)*)
+        where
+            $($name: Display,)*
+        {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn fmt_joined<S>(&self, separator: &S, f: &mut Formatter) -> fmt::Result
+            where
+                S: Display + ?Sized,
+            {
+                let ($($name,)*) = self;
+                let mut joiner = Joiner::new(f, separator);
+
+                $(joiner.entry($name.as_ref(), $name::fmt, S::fmt)?;)*
+
+                Ok(())
+            }
+        }
+
+        impl<$($name,)*> DebugJoin for ($(Option<$name>,)*)
+        where
+            $($name: Debug,)*
+        {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn fmt_joined<S>(&self, separator: &S, f: &mut Formatter) -> fmt::Result
+            where
+                S: Debug + ?Sized,
+            {
+                let ($($name,)*) = self;
+                let mut joiner = Joiner::new(f, separator);
+
+                $(joiner.entry($name.as_ref(), $name::fmt, S::fmt)?;)*
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_join!();
+impl_join!(T0);
+impl_join!(T0 T1);
+impl_join!(T0 T1 T2);
+impl_join!(T0 T1 T2 T3);
+impl_join!(T0 T1 T2 T3 T4);
+impl_join!(T0 T1 T2 T3 T4 T5);
+impl_join!(T0 T1 T2 T3 T4 T5 T6);
+impl_join!(T0 T1 T2 T3 T4 T5 T6 T7);
+impl_join!(T0 T1 T2 T3 T4 T5 T6 T7 T8);
+impl_join!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9);
+impl_join!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10);
+impl_join!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11);
+
+/// [`Debug`] or [`Display`] a tuple of optional [`Debug`] objects with a separator that implements [`Debug`].
+pub struct FmtDebugJoin<T, S> {
+    values: T,
+    separator: S,
+}
+
+impl<T, S> FmtDebugJoin<T, S> {
+    const fn new(values: T, separator: S) -> Self {
+        Self { values, separator }
+    }
+}
+
+impl<T, S> Debug for FmtDebugJoin<T, S>
+where
+    T: DebugJoin,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.values.fmt_joined(&self.separator, f)
+    }
+}
+
+impl<T, S> Display for FmtDebugJoin<T, S>
+where
+    T: DebugJoin,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] a tuple of optional [`Display`] objects with a separator that implements [`Display`].
+pub struct FmtDisplayJoin<T, S> {
+    values: T,
+    separator: S,
+}
+
+impl<T, S> FmtDisplayJoin<T, S> {
+    const fn new(values: T, separator: S) -> Self {
+        Self { values, separator }
+    }
+}
+
+impl<T, S> Debug for FmtDisplayJoin<T, S>
+where
+    T: DisplayJoin,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.values.fmt_joined(&self.separator, f)
+    }
+}
+
+impl<T, S> Display for FmtDisplayJoin<T, S>
+where
+    T: DisplayJoin,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a tuple of optional [`Debug`] objects with specified separator that
+/// implements [`Debug`]. `None` values are skipped.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_debug_join((Some('a'), None::<u32>, Some("b")), 3);
+///
+/// assert_eq!(format!("{fmt:?}"), "'a'3\"b\"");
+/// assert_eq!(format!("{fmt}"), "'a'3\"b\"");
+/// ```
+pub const fn fmt_debug_join<T, S>(values: T, separator: S) -> FmtDebugJoin<T, S>
+where
+    T: DebugJoin,
+    S: Debug,
+{
+    FmtDebugJoin::new(values, separator)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a tuple of optional [`Display`] objects with specified separator
+/// that implements [`Display`]. `None` values are skipped.
+///
+/// See also [`fmt_join`](`crate::fmt_join`) for a more convenient syntax.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_display_join((Some('a'), None::<u32>, Some("b")), ", ");
+///
+/// assert_eq!(format!("{fmt:?}"), "a, b");
+/// assert_eq!(format!("{fmt}"), "a, b");
+/// ```
+pub const fn fmt_display_join<T, S>(values: T, separator: S) -> FmtDisplayJoin<T, S>
+where
+    T: DisplayJoin,
+    S: Display,
+{
+    FmtDisplayJoin::new(values, separator)
+}
+
+/// Joins [`Display`](`core::fmt::Display`) values of different types with a separator, without dynamic dispatch.
+///
+/// Values prefixed with `?` are [`Option`]s, which are skipped if they are `None`.
+///
+/// Example:
+///
+/// ```rust
+/// let name = "foo";
+/// let version = None::<u32>;
+/// let fmt = fmt_tools::fmt_join!((name, ?version, 'x', ?Some(2)), "-");
+///
+/// assert_eq!(format!("{fmt:?}"), "foo-x-2");
+/// assert_eq!(format!("{fmt}"), "foo-x-2");
+/// ```
+#[macro_export]
+macro_rules! fmt_join {
+    (@values [$($values:tt)*]) => {
+        ($($values)*)
+    };
+    (@values [$($values:tt)*] ? $value:expr $(, $($rest:tt)*)?) => {
+        $crate::fmt_join!(@values [$($values)* $value,] $($($rest)*)?)
+    };
+    (@values [$($values:tt)*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::fmt_join!(@values [$($values)* ::core::option::Option::Some($value),] $($($rest)*)?)
+    };
+    (($($values:tt)*), $separator:expr $(,)?) => {
+        $crate::fmt_display_join($crate::fmt_join!(@values [] $($values)*), $separator)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::{self, Display, Formatter};
+
+    #[test]
+    fn test_debug_join() {
+        #[derive(Debug)]
+        struct Foo;
+
+        #[derive(Debug)]
+        struct Bar;
+
+        #[derive(Debug)]
+        struct Baz;
+
+        let test_cases = [
+            (super::fmt_debug_join((None::<Foo>, None::<Bar>, None::<Foo>), Baz), ""),
+            (super::fmt_debug_join((Some(Foo), None, None::<Foo>), Baz), "Foo"),
+            (super::fmt_debug_join((None, Some(Bar), None::<Foo>), Baz), "Bar"),
+            (super::fmt_debug_join((Some(Foo), Some(Bar), None), Baz), "FooBazBar"),
+            (super::fmt_debug_join((None, Some(Bar), Some(Foo)), Baz), "BarBazFoo"),
+            (
+                super::fmt_debug_join((Some(Foo), Some(Bar), Some(Foo)), Baz),
+                "FooBazBarBazFoo",
+            ),
+        ];
+
+        for (fmt, expected) in test_cases {
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_display_join() {
+        struct Foo;
+
+        impl Display for Foo {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("foo")
+            }
+        }
+
+        let test_cases = [
+            (super::fmt_display_join((None::<Foo>, None::<u32>), ", "), ""),
+            (super::fmt_display_join((Some(Foo), None), ", "), "foo"),
+            (super::fmt_display_join((None, Some(7)), ", "), "7"),
+            (super::fmt_display_join((Some(Foo), Some(7)), ", "), "foo, 7"),
+        ];
+
+        for (fmt, expected) in test_cases {
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_fmt_join_macro() {
+        let none = None::<char>;
+
+        assert_eq!(std::format!("{}", crate::fmt_join!((), ", ")), "");
+        assert_eq!(std::format!("{}", crate::fmt_join!((1), ", ")), "1");
+        assert_eq!(std::format!("{}", crate::fmt_join!((?none), ", ")), "");
+        assert_eq!(std::format!("{}", crate::fmt_join!((1, 'a', "b"), ", ")), "1, a, b");
+        assert_eq!(
+            std::format!("{}", crate::fmt_join!((?none, 'a', ?Some(2),), ", ")),
+            "a, 2"
+        );
+        assert_eq!(std::format!("{}", crate::fmt_join!((1, ?none), ", ",)), "1");
+    }
+}
@@ -0,0 +1,279 @@
+use crate::fmt_visitor::FmtVisitor;
+use crate::node::{Node, Scalar};
+use crate::utilities;
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+fn write_string(args: Arguments, f: &mut Formatter) -> fmt::Result {
+    f.write_char('"')?;
+
+    utilities::try_for_each_char(args, |c| match c {
+        '"' => f.write_str("\\\""),
+        '\\' => f.write_str("\\\\"),
+        '\u{8}' => f.write_str("\\b"),
+        '\t' => f.write_str("\\t"),
+        '\n' => f.write_str("\\n"),
+        '\u{c}' => f.write_str("\\f"),
+        '\r' => f.write_str("\\r"),
+        '\0'..='\u{1f}' => f.write_fmt(format_args!("\\u{:04x}", u32::from(c))),
+        c => f.write_char(c),
+    })?;
+
+    f.write_char('"')
+}
+
+/// A [`FmtVisitor`] that writes values as JSON. Map keys are written as strings, and non-finite floating point values
+/// are written as `null`.
+///
+/// If the alternate flag of the formatter is specified, values are pretty-printed with two spaces of indentation.
+pub struct JsonVisitor<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    depth: usize,
+    has_entries: bool,
+}
+
+impl<'a, 'b> JsonVisitor<'a, 'b> {
+    /// Creates a new `JsonVisitor` that writes to `f`.
+    pub fn new(f: &'a mut Formatter<'b>) -> Self {
+        Self {
+            f,
+            depth: 0,
+            has_entries: false,
+        }
+    }
+
+    fn begin(&mut self, opening: char) -> fmt::Result {
+        self.depth += 1;
+        self.has_entries = false;
+
+        self.f.write_char(opening)
+    }
+
+    fn write_new_line(&mut self) -> fmt::Result {
+        self.f.write_char('\n')?;
+
+        (0..self.depth).try_for_each(|_| self.f.write_str("  "))
+    }
+
+    fn write_separator(&mut self) -> fmt::Result {
+        if self.has_entries {
+            self.f.write_char(',')?;
+        }
+
+        self.has_entries = true;
+
+        if self.f.alternate() {
+            self.write_new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn end(&mut self, closing: char) -> fmt::Result {
+        self.depth = self.depth.saturating_sub(1);
+
+        if self.has_entries && self.f.alternate() {
+            self.write_new_line()?;
+        }
+
+        // The parent collection, if any, has at least one entry, which is the one that just ended.
+        self.has_entries = true;
+
+        self.f.write_char(closing)
+    }
+}
+
+impl FmtVisitor for JsonVisitor<'_, '_> {
+    fn scalar(&mut self, scalar: Scalar) -> fmt::Result {
+        match scalar {
+            Scalar::Null => self.f.write_str("null"),
+            Scalar::Bool(value) => self.f.write_str(if value { "true" } else { "false" }),
+            Scalar::Integer(value) => self.f.write_fmt(format_args!("{value}")),
+            Scalar::Float(value) => {
+                if value.is_finite() {
+                    self.f.write_fmt(format_args!("{value:?}"))
+                } else {
+                    self.f.write_str("null")
+                }
+            }
+            Scalar::String(value) => write_string(format_args!("{value}"), self.f),
+        }
+    }
+
+    fn begin_list(&mut self) -> fmt::Result {
+        self.begin('[')
+    }
+
+    fn entry(&mut self) -> fmt::Result {
+        self.write_separator()
+    }
+
+    fn begin_map(&mut self) -> fmt::Result {
+        self.begin('{')
+    }
+
+    fn key(&mut self, key: &dyn Display) -> fmt::Result {
+        self.write_separator()?;
+
+        write_string(format_args!("{key}"), self.f)
+    }
+
+    fn value(&mut self) -> fmt::Result {
+        self.f.write_str(if self.f.alternate() { ": " } else { ":" })
+    }
+
+    fn end_list(&mut self) -> fmt::Result {
+        self.end(']')
+    }
+
+    fn end_map(&mut self) -> fmt::Result {
+        self.end('}')
+    }
+}
+
+/// [`Debug`] or [`Display`] a [`Node`] as JSON.
+///
+/// If the alternate flag is specified in the format string, the JSON is pretty-printed with two spaces of indentation.
+pub struct FmtJson<T>
+where
+    T: ?Sized,
+{
+    value: T,
+}
+
+impl<T> FmtJson<T> {
+    const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> Debug for FmtJson<T>
+where
+    T: Node + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.visit(&mut JsonVisitor::new(f))
+    }
+}
+
+impl<T> Display for FmtJson<T>
+where
+    T: Node + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Node`] as JSON.
+///
+/// If the alternate flag is specified in the format string, the JSON is pretty-printed with two spaces of indentation.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::Node;
+///
+/// let fmt = fmt_tools::fmt_json(fmt_tools::fmt_debug_map(|| {
+///     [("name", &"app" as &dyn Node), ("ports", &[80, 443]), ("tls", &None::<bool>)]
+/// }));
+///
+/// assert_eq!(format!("{fmt:?}"), r#"{"name":"app","ports":[80,443],"tls":null}"#);
+/// assert_eq!(format!("{fmt}"), r#"{"name":"app","ports":[80,443],"tls":null}"#);
+///
+/// assert_eq!(
+///     format!("{fmt:#}"),
+///     r#"{
+///   "name": "app",
+///   "ports": [
+///     80,
+///     443
+///   ],
+///   "tls": null
+/// }"#,
+/// );
+/// ```
+pub const fn fmt_json<T>(value: T) -> FmtJson<T>
+where
+    T: Node,
+{
+    FmtJson::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtJson;
+    use crate::node::{Node, NodeKind, Scalar};
+    use core::fmt;
+
+    /// A list nested `depth` levels deep.
+    struct Nested(usize);
+
+    impl Node for Nested {
+        fn kind(&self) -> NodeKind<'_> {
+            if self.0 == 0 {
+                NodeKind::Scalar(Scalar::Null)
+            } else {
+                NodeKind::List
+            }
+        }
+
+        fn for_each_item(&self, item_fn: &mut dyn FnMut(&dyn Node) -> fmt::Result) -> fmt::Result {
+            item_fn(&Self(self.0 - 1))
+        }
+    }
+
+    #[test]
+    fn test_fmt_json() {
+        let empty_map = crate::fmt_debug_map(|| [(1, 2); 0]);
+        let map = crate::fmt_debug_map(|| [("a", [1]), ("b\"", [2])]);
+        let set = crate::fmt_debug_set(|| [&empty_map, &empty_map]);
+
+        let test_cases: [(&dyn Node, &str, &str); 12] = [
+            (&None::<u32>, "null", "null"),
+            (&false, "false", "false"),
+            (&-5_i64, "-5", "-5"),
+            (&0.5, "0.5", "0.5"),
+            (&0.1_f32, "0.1", "0.1"),
+            (&f64::INFINITY, "null", "null"),
+            (&"a\"\\\n\u{1f}é", r#""a\"\\\n\u001fé""#, r#""a\"\\\n\u001fé""#),
+            (&[0_u32; 0], "[]", "[]"),
+            (&empty_map, "{}", "{}"),
+            (
+                &[[1, 2], [3, 4]],
+                "[[1,2],[3,4]]",
+                "[\n  [\n    1,\n    2\n  ],\n  [\n    3,\n    4\n  ]\n]",
+            ),
+            (
+                &map,
+                r#"{"a":[1],"b\"":[2]}"#,
+                "{\n  \"a\": [\n    1\n  ],\n  \"b\\\"\": [\n    2\n  ]\n}",
+            ),
+            (&set, "[{},{}]", "[\n  {},\n  {}\n]"),
+        ];
+
+        for (value, expected, expected_alternate) in test_cases {
+            let fmt = super::fmt_json(value);
+            let unsized_fmt: &FmtJson<dyn Node> = &super::fmt_json(value);
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+            assert_eq!(std::format!("{fmt:#}"), expected_alternate);
+        }
+    }
+
+    #[test]
+    fn test_fmt_json_deep_nesting() {
+        let fmt = super::fmt_json(Nested(200));
+
+        assert_eq!(
+            std::format!("{fmt}"),
+            std::format!("{}null{}", "[".repeat(200), "]".repeat(200))
+        );
+
+        let alternate = std::format!("{fmt:#}");
+
+        assert_eq!(alternate.lines().count(), 401);
+        assert!(alternate.contains(&std::format!("\n{}null\n{}]\n", "  ".repeat(200), "  ".repeat(199))));
+    }
+}
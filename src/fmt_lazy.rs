@@ -0,0 +1,82 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// [`Debug`] or [`Display`] a [`Display`] value that is computed by a function object each time it is formatted.
+pub struct FmtLazy<F>
+where
+    F: ?Sized,
+{
+    value_fn: F,
+}
+
+impl<F> FmtLazy<F> {
+    const fn new(value_fn: F) -> Self {
+        Self { value_fn }
+    }
+}
+
+impl<F, T> Debug for FmtLazy<F>
+where
+    F: Fn() -> T + ?Sized,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&(self.value_fn)(), f)
+    }
+}
+
+impl<F, T> Display for FmtLazy<F>
+where
+    F: Fn() -> T + ?Sized,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] the value returned by the provided function object, using the
+/// [`Display`] implementation of the returned value. The function object is only called when the object is actually
+/// formatted.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_lazy(|| "foo".repeat(2));
+///
+/// assert_eq!(format!("{fmt:?}"), "foofoo");
+/// assert_eq!(format!("{fmt}"), "foofoo");
+/// ```
+pub const fn fmt_lazy<F, T>(value_fn: F) -> FmtLazy<F>
+where
+    F: Fn() -> T,
+    T: Display,
+{
+    FmtLazy::new(value_fn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtLazy;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_fmt_lazy() {
+        let calls = Cell::new(0);
+
+        let fmt = super::fmt_lazy(|| {
+            calls.set(calls.get() + 1);
+
+            "foo"
+        });
+
+        assert_eq!(calls.get(), 0);
+
+        let unsized_fmt: &FmtLazy<dyn Fn() -> &'static str> = &fmt;
+
+        assert_eq!(std::format!("{fmt:?}"), "foo");
+        assert_eq!(std::format!("{fmt}"), "foo");
+        assert_eq!(std::format!("{unsized_fmt:?}"), "foo");
+        assert_eq!(std::format!("{unsized_fmt:>5}"), "  foo");
+        assert_eq!(calls.get(), 4);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::fmt_separated_list;
+use crate::utilities;
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+fn is_special_char(c: char) -> bool {
+    c.is_control() || c.is_whitespace() || matches!(c, '=' | '"')
+}
+
+fn write_key(args: Arguments, f: &mut Formatter) -> fmt::Result {
+    // Keys can not be quoted, so characters that would break parsing are replaced with `_`, and empty keys are written
+    // as `_`.
+    let mut is_empty = true;
+
+    utilities::try_for_each_char(args, |c| {
+        is_empty = false;
+
+        f.write_char(if is_special_char(c) { '_' } else { c })
+    })?;
+
+    if is_empty {
+        f.write_char('_')
+    } else {
+        Ok(())
+    }
+}
+
+fn write_value(args: Arguments, f: &mut Formatter) -> fmt::Result {
+    let mut needs_quoting = false;
+
+    utilities::try_for_each_char(args, |c| {
+        needs_quoting |= is_special_char(c);
+
+        Ok(())
+    })?;
+
+    if needs_quoting {
+        f.write_char('"')?;
+
+        utilities::try_for_each_char(args, |c| match c {
+            '"' => f.write_str("\\\""),
+            '\\' => f.write_str("\\\\"),
+            '\n' => f.write_str("\\n"),
+            '\r' => f.write_str("\\r"),
+            '\t' => f.write_str("\\t"),
+            c if c.is_control() => f.write_fmt(format_args!("\\u{:04x}", u32::from(c))),
+            c => f.write_char(c),
+        })?;
+
+        f.write_char('"')
+    } else {
+        utilities::try_for_each_char(args, |c| f.write_char(c))
+    }
+}
+
+/// [`Debug`] or [`Display`] a list of `(Display, Display)` objects as [logfmt](https://brandur.org/logfmt) key/value
+/// pairs.
+pub struct FmtLogfmt<F>
+where
+    F: ?Sized,
+{
+    values_fn: F,
+}
+
+impl<F> FmtLogfmt<F> {
+    const fn new(values_fn: F) -> Self {
+        Self { values_fn }
+    }
+}
+
+impl<F, I, K, V> Debug for FmtLogfmt<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_separated_list::fmt_separated(
+            (self.values_fn)(),
+            f,
+            |(key, value), f| {
+                write_key(format_args!("{key}"), f)?;
+                f.write_char('=')?;
+                write_value(format_args!("{value}"), f)
+            },
+            |f| f.write_char(' '),
+        )
+    }
+}
+
+impl<F, I, K, V> Display for FmtLogfmt<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of `(Display, Display)` objects as
+/// [logfmt](https://brandur.org/logfmt) key/value pairs.
+///
+/// Values are quoted only if they contain whitespace, `=`, `"` or control characters. Quoted values have `"`, `\` and
+/// control characters escaped. Characters in keys that would need quoting are replaced with `_`, and empty keys are
+/// written as `_`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_logfmt(|| [("level", "info"), ("msg", "hello world"), ("path", "")]);
+///
+/// assert_eq!(format!("{fmt:?}"), r#"level=info msg="hello world" path="#);
+/// assert_eq!(format!("{fmt}"), r#"level=info msg="hello world" path="#);
+/// ```
+pub const fn fmt_logfmt<F, I, K, V>(values_fn: F) -> FmtLogfmt<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    FmtLogfmt::new(values_fn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtLogfmt;
+
+    #[test]
+    fn test_fmt_logfmt() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[(&str, &str)], ""),
+            (&[("a", "1")], "a=1"),
+            (&[("a", "1"), ("b", "2")], "a=1 b=2"),
+            (&[("a", "")], "a="),
+            (&[("a", "x y")], r#"a="x y""#),
+            (&[("a", "x=y")], r#"a="x=y""#),
+            (&[("a", r#"say "hi""#)], r#"a="say \"hi\"""#),
+            (&[("a", r"C:\dir")], r"a=C:\dir"),
+            (&[("a", "C:\\my dir")], r#"a="C:\\my dir""#),
+            (&[("a", "x\ny\r\tz\u{1}")], r#"a="x\ny\r\tz\u0001""#),
+            (&[("a", "日本")], "a=日本"),
+            (&[("a", "x\u{a0}y")], "a=\"x\u{a0}y\""),
+            (&[("a", "x\u{2028}y\u{85}")], "a=\"x\u{2028}y\\u0085\""),
+            (&[("my key=", "1")], "my_key_=1"),
+            (&[("", "1"), ("", "")], "_=1 _="),
+            (&[("my\u{3000}key\u{9f}", "1")], "my_key_=1"),
+        ];
+
+        for (values, expected) in test_cases {
+            let fmt = super::fmt_logfmt(|| values.iter().copied());
+            let unsized_fmt: &FmtLogfmt<dyn Fn() -> _> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_fmt_logfmt_mixed_types() {
+        let fmt = super::fmt_logfmt(|| [("count", 3), ("delta", -4)]);
+
+        assert_eq!(std::format!("{fmt}"), "count=3 delta=-4");
+        assert_eq!(std::format!("{fmt:>20}"), "count=3 delta=-4");
+    }
+}
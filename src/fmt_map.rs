@@ -1,6 +1,10 @@
-use crate::{fmt_display, FmtDisplay};
+use crate::node::{Node, NodeKind};
+use crate::{fmt_display, fmt_fn, FmtDisplay};
 use core::fmt::{self, Debug, Display, Formatter};
 
+#[cfg(feature = "ufmt")]
+use crate::fmt_ufmt_fn::UDisplayAsDebug;
+
 /// [`Debug`] or [`Display`] a list of `(Debug, Debug)` objects as a map.
 pub struct FmtDebugMap<F>
 where
@@ -41,6 +45,126 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<F, I, K, V> defmt::Format for FmtDebugMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: defmt::Format,
+    V: defmt::Format,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{{");
+
+        crate::fmt_separated_list::defmt_separated(
+            (self.values_fn)(),
+            fmt,
+            |(key, value), fmt| defmt::write!(fmt, "{}: {}", key, value),
+            |fmt| defmt::write!(fmt, ", "),
+        );
+
+        defmt::write!(fmt, "}}");
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I, K, V> ufmt::uDebug for FmtDebugMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: ufmt::uDebug,
+    V: ufmt::uDebug,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.debug_map()?.entries((self.values_fn)())?.finish()
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I, K, V> ufmt::uDisplay for FmtDebugMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: ufmt::uDebug,
+    V: ufmt::uDebug,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDebug::fmt(self, f)
+    }
+}
+
+impl<F, I, K, V> Node for FmtDebugMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Node,
+{
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::Map
+    }
+
+    fn for_each_entry(&self, entry_fn: &mut dyn FnMut(&dyn Display, &dyn Node) -> fmt::Result) -> fmt::Result {
+        (self.values_fn)()
+            .into_iter()
+            .try_for_each(|(key, value)| entry_fn(&key, &value))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F, I, K, V> serde::Serialize for FmtDebugMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: serde::Serialize,
+    V: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map((self.values_fn)())
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<F, I, K, V> valuable::Valuable for FmtDebugMap<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator<Item = (K, V)>,
+    K: valuable::Valuable,
+    V: valuable::Valuable,
+{
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Mappable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        (self.values_fn)()
+            .into_iter()
+            .for_each(|(key, value)| visit.visit_entry(key.as_value(), value.as_value()));
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<F, I, K, V> valuable::Mappable for FmtDebugMap<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator<Item = (K, V)>,
+    K: valuable::Valuable,
+    V: valuable::Valuable,
+{
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.values_fn)().into_iter().size_hint()
+    }
+}
+
 /// [`Debug`] or [`Display`] a list of `(Display, Display)` objects as a map.
 pub struct FmtDisplayMap<F>
 where
@@ -89,6 +213,135 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<F, I, K, V> defmt::Format for FmtDisplayMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{{");
+
+        crate::fmt_separated_list::defmt_separated(
+            (self.values_fn)(),
+            fmt,
+            |(key, value), fmt| defmt::write!(fmt, "{}: {}", defmt::Display2Format(key), defmt::Display2Format(value)),
+            |fmt| defmt::write!(fmt, ", "),
+        );
+
+        defmt::write!(fmt, "}}");
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I, K, V> ufmt::uDebug for FmtDisplayMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: ufmt::uDisplay,
+    V: ufmt::uDisplay,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let entries = (self.values_fn)()
+            .into_iter()
+            .map(|(key, value)| (UDisplayAsDebug(key), UDisplayAsDebug(value)));
+
+        f.debug_map()?.entries(entries)?.finish()
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I, K, V> ufmt::uDisplay for FmtDisplayMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: ufmt::uDisplay,
+    V: ufmt::uDisplay,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDebug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F, I, K, V> serde::Serialize for FmtDisplayMap<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(
+            (self.values_fn)()
+                .into_iter()
+                .map(|(key, value)| (fmt_display(key), fmt_display(value))),
+        )
+    }
+}
+
+/// [`Debug`] or [`Display`] a list of `(K, V)` objects as a map, formatting keys and values with function objects.
+pub struct FmtDebugMapWith<F, KW, VW>
+where
+    F: ?Sized,
+{
+    key_writer: KW,
+    value_writer: VW,
+    values_fn: F,
+}
+
+impl<F, KW, VW> FmtDebugMapWith<F, KW, VW> {
+    const fn new(values_fn: F, key_writer: KW, value_writer: VW) -> Self {
+        Self {
+            key_writer,
+            value_writer,
+            values_fn,
+        }
+    }
+}
+
+impl<F, KW, VW, I, K, V> Debug for FmtDebugMapWith<F, KW, VW>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    KW: Fn(&K, &mut Formatter) -> fmt::Result,
+    VW: Fn(&V, &mut Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let entries = (self.values_fn)().into_iter().map(|(key, value)| {
+            (
+                fmt_fn(move |f| (self.key_writer)(&key, f)),
+                fmt_fn(move |f| (self.value_writer)(&value, f)),
+            )
+        });
+
+        f.debug_map().entries(entries).finish()
+    }
+}
+
+impl<F, KW, VW, I, K, V> Display for FmtDebugMapWith<F, KW, VW>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    KW: Fn(&K, &mut Formatter) -> fmt::Result,
+    VW: Fn(&V, &mut Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Creates an object that [`Debug`] or [`Display`] a list of `(Debug, Debug)` objects as a map.
 ///
 /// Example:
@@ -129,9 +382,38 @@ where
     FmtDisplayMap::new(values_fn)
 }
 
+/// Creates an object that [`Debug`] or [`Display`] a list of `(K, V)` objects as a map, formatting keys and values
+/// with the provided function objects.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_debug_map_with(
+///     || ('a'..'d').zip(10..),
+///     |key, f| write!(f, "{}", key.to_ascii_uppercase()),
+///     |value, f| write!(f, "{value:#x}"),
+/// );
+///
+/// assert_eq!(format!("{fmt:?}"), "{A: 0xa, B: 0xb, C: 0xc}");
+/// assert_eq!(format!("{fmt}"), "{A: 0xa, B: 0xb, C: 0xc}");
+/// ```
+pub const fn fmt_debug_map_with<F, KW, VW, I, K, V>(
+    values_fn: F,
+    key_writer: KW,
+    value_writer: VW,
+) -> FmtDebugMapWith<F, KW, VW>
+where
+    F: Fn() -> I,
+    I: IntoIterator<Item = (K, V)>,
+    KW: Fn(&K, &mut Formatter) -> fmt::Result,
+    VW: Fn(&V, &mut Formatter) -> fmt::Result,
+{
+    FmtDebugMapWith::new(values_fn, key_writer, value_writer)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{FmtDebugMap, FmtDisplayMap};
+    use super::{FmtDebugMap, FmtDebugMapWith, FmtDisplayMap};
     use core::fmt::{self, Display, Formatter};
 
     #[test]
@@ -197,4 +479,112 @@ mod tests {
             assert_eq!(std::format!("{unsized_fmt_map}"), expected);
         }
     }
+
+    #[test]
+    fn test_debug_fmt_map_with() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[(u32, char)], "{}"),
+            (&[(1, 'a')], "{<1>: 'a'}"),
+            (&[(1, 'a'), (2, 'b')], "{<1>: 'a', <2>: 'b'}"),
+            (&[(1, 'a'), (2, 'b'), (3, 'c')], "{<1>: 'a', <2>: 'b', <3>: 'c'}"),
+        ];
+
+        for (values, expected) in test_cases {
+            let fmt_map = super::fmt_debug_map_with(
+                || values.iter().copied(),
+                |key, f| f.write_fmt(format_args!("<{key}>")),
+                |value, f| f.write_fmt(format_args!("{value:?}")),
+            );
+
+            let unsized_fmt_map: &FmtDebugMapWith<dyn Fn() -> _, _, _> = &fmt_map;
+
+            assert_eq!(std::format!("{fmt_map:?}"), expected);
+            assert_eq!(std::format!("{fmt_map}"), expected);
+            assert_eq!(std::format!("{unsized_fmt_map:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt_map}"), expected);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_fmt_map() {
+        let debug_map = super::fmt_debug_map(|| [("a", [1]), ("b", [2])]);
+        let display_map = super::fmt_display_map(|| [('a', 1), ('b', 2)]);
+        let unsized_display_map: &FmtDisplayMap<dyn Fn() -> _> = &display_map;
+
+        assert_eq!(serde_json::to_string(&debug_map).unwrap(), r#"{"a":[1],"b":[2]}"#);
+        assert_eq!(serde_json::to_string(&display_map).unwrap(), r#"{"a":"1","b":"2"}"#);
+        assert_eq!(
+            serde_json::to_string(unsized_display_map).unwrap(),
+            r#"{"a":"1","b":"2"}"#
+        );
+    }
+
+    #[cfg(feature = "valuable")]
+    #[test]
+    fn test_valuable_fmt_debug_map() {
+        use valuable::{Mappable, Valuable, Value};
+
+        let inner = super::fmt_debug_map(|| [("x", 1)]);
+        let map = super::fmt_debug_map(|| [("a", &inner), ("b", &inner)]);
+
+        assert!(matches!(map.as_value(), Value::Mappable(_)));
+        assert_eq!(map.size_hint(), (2, Some(2)));
+        assert_eq!(
+            std::format!("{:?}", map.as_value()),
+            r#"{"a": {"x": 1}, "b": {"x": 1}}"#
+        );
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_ufmt_fmt_map() {
+        let debug_map = super::fmt_debug_map(|| [(1, Some(2)), (3, None)]);
+        let display_map = super::fmt_display_map(|| [("a", 1), ("b", 2)]);
+        let mut buffer = std::string::String::new();
+
+        ufmt::uwrite!(buffer, "{:?} {} {}", debug_map, debug_map, display_map).unwrap();
+
+        assert_eq!(buffer, "{1: Some(2), 3: None} {1: Some(2), 3: None} {a: 1, b: 2}");
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_fmt_map() {
+        fn assert_format<T>(_: &T)
+        where
+            T: defmt::Format + ?Sized,
+        {
+        }
+
+        let debug_map = super::fmt_debug_map(|| [(1, 2)]);
+        let display_map = super::fmt_display_map(|| [("a", "b")]);
+        let unsized_debug_map: &FmtDebugMap<dyn Fn() -> [(i32, i32); 1]> = &debug_map;
+        let unsized_display_map: &FmtDisplayMap<dyn Fn() -> [(&'static str, &'static str); 1]> = &display_map;
+
+        assert_format(&debug_map);
+        assert_format(&display_map);
+        assert_format(unsized_debug_map);
+        assert_format(unsized_display_map);
+
+        let (index, bytes) = crate::utilities::defmt_encode(&display_map);
+        let istr = |offset: u16| (index + offset).to_le_bytes();
+
+        assert_eq!(
+            bytes,
+            [
+                &istr(0)[..], // Format sequence.
+                &istr(1),     // "{{"
+                &istr(2),     // "{}: {}"
+                &istr(3),     // Display hint.
+                b"a\xff",
+                &istr(4), // Display hint.
+                b"b\xff",
+                &istr(5), // "}}"
+                &[0, 0],  // End of the format sequence.
+            ]
+            .concat()
+        );
+    }
 }
@@ -0,0 +1,282 @@
+use crate::utilities::StackBuffer;
+use core::fmt::{self, Alignment, Debug, Display, Formatter, Write};
+
+/// The maximum precision, larger precisions are clamped to it so that the computation can not overflow.
+const MAX_PRECISION: u32 = 16;
+
+/// Enough for the longest number, which is a percentage of [`u64::MAX`] with [`MAX_PRECISION`] digits, and for the
+/// longest suffix.
+const BUFFER_SIZE: usize = 64;
+
+/// Writes `sign`, `number` and `suffix` padded to the width specified in `f`. Zero-padding is applied between `sign`
+/// and `number` only if `number` is numeric, otherwise the fill and alignment of `f` are used, defaulting to right
+/// alignment.
+fn write_padded(sign: &str, number: &str, is_numeric: bool, suffix: &str, f: &mut Formatter) -> fmt::Result {
+    let padding = f.width().map_or(0, |width| {
+        width.saturating_sub(sign.len() + number.len() + suffix.len())
+    });
+
+    if is_numeric && f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        (0..padding).try_for_each(|_| f.write_char('0'))?;
+        f.write_str(number)?;
+
+        return f.write_str(suffix);
+    }
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    f.write_str(sign)?;
+    f.write_str(number)?;
+    f.write_str(suffix)?;
+    (0..after).try_for_each(|_| f.write_char(fill))
+}
+
+/// Writes `numerator * multiplier / denominator` rounded half up, with the precision specified in `f` or
+/// `default_precision`, followed by `unit`. If the alternate flag is specified, the numerator and denominator are
+/// appended like ` (12/34)`. Zero-padding only applies to the number, a zero denominator is written as `n/a`, which is
+/// never zero-padded.
+fn fmt_fraction(
+    numerator: u64,
+    denominator: u64,
+    multiplier: u64,
+    default_precision: u32,
+    unit: &str,
+    f: &mut Formatter,
+) -> fmt::Result {
+    let mut number = StackBuffer::<BUFFER_SIZE>::new();
+    let mut suffix = StackBuffer::<BUFFER_SIZE>::new();
+    let is_numeric = denominator != 0;
+
+    if is_numeric {
+        let precision = f
+            .precision()
+            .map_or(default_precision, |precision| {
+                u32::try_from(precision).unwrap_or(MAX_PRECISION)
+            })
+            .min(MAX_PRECISION);
+
+        let scale = 10_u128.pow(precision);
+        let denominator = u128::from(denominator);
+        let scaled = (u128::from(numerator) * u128::from(multiplier) * scale + denominator / 2) / denominator;
+        let integer = scaled / scale;
+
+        if precision == 0 {
+            number.write_fmt(format_args!("{integer}"))?;
+        } else {
+            let fraction = scaled % scale;
+            let width = precision as usize;
+
+            number.write_fmt(format_args!("{integer}.{fraction:0width$}"))?;
+        }
+
+        suffix.write_str(unit)?;
+    } else {
+        number.write_str("n/a")?;
+    }
+
+    if f.alternate() {
+        suffix.write_fmt(format_args!(" ({numerator}/{denominator})"))?;
+    }
+
+    let sign = if is_numeric && f.sign_plus() { "+" } else { "" };
+
+    write_padded(sign, number.as_str(), is_numeric, suffix.as_str(), f)
+}
+
+/// [`Debug`] or [`Display`] a fraction as a percentage.
+///
+/// The precision is taken from the format string, defaulting to 0. If the alternate flag is specified, the numerator
+/// and denominator are appended like `35% (12/34)`. The width, fill, alignment, sign and zero-padding flags of the
+/// format string are honored, zero-padding is only applied to numbers.
+pub struct FmtPercent {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl FmtPercent {
+    const fn new(numerator: u64, denominator: u64) -> Self {
+        Self { numerator, denominator }
+    }
+}
+
+impl Debug for FmtPercent {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_fraction(self.numerator, self.denominator, 100, 0, "%", f)
+    }
+}
+
+impl Display for FmtPercent {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] a fraction as a decimal ratio.
+///
+/// The precision is taken from the format string, defaulting to 2. If the alternate flag is specified, the numerator
+/// and denominator are appended like `0.35 (12/34)`. The width, fill, alignment, sign and zero-padding flags of the
+/// format string are honored, zero-padding is only applied to numbers.
+pub struct FmtRatio {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl FmtRatio {
+    const fn new(numerator: u64, denominator: u64) -> Self {
+        Self { numerator, denominator }
+    }
+}
+
+impl Debug for FmtRatio {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_fraction(self.numerator, self.denominator, 1, 2, "", f)
+    }
+}
+
+impl Display for FmtRatio {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] `numerator / denominator` as a percentage, rounded half up. A zero
+/// denominator is written as `n/a`.
+///
+/// The precision is taken from the format string, defaulting to 0, and is clamped to 16. If the alternate flag is
+/// specified, the numerator and denominator are appended. The width, fill, alignment, sign and zero-padding flags of
+/// the format string are honored, zero-padding is only applied to numbers.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_percent(12, 34);
+///
+/// assert_eq!(format!("{fmt:?}"), "35%");
+/// assert_eq!(format!("{fmt}"), "35%");
+/// assert_eq!(format!("{fmt:.2}"), "35.29%");
+/// assert_eq!(format!("{fmt:#}"), "35% (12/34)");
+/// assert_eq!(format!("{fmt:>6.1}"), " 35.3%");
+/// assert_eq!(format!("{:#}", fmt_tools::fmt_percent(0, 0)), "n/a (0/0)");
+/// ```
+#[must_use]
+pub const fn fmt_percent(numerator: u64, denominator: u64) -> FmtPercent {
+    FmtPercent::new(numerator, denominator)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] `numerator / denominator` as a decimal ratio, rounded half up. A
+/// zero denominator is written as `n/a`.
+///
+/// The precision is taken from the format string, defaulting to 2, and is clamped to 16. If the alternate flag is
+/// specified, the numerator and denominator are appended. The width, fill, alignment, sign and zero-padding flags of
+/// the format string are honored, zero-padding is only applied to numbers.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_ratio(12, 34);
+///
+/// assert_eq!(format!("{fmt:?}"), "0.35");
+/// assert_eq!(format!("{fmt}"), "0.35");
+/// assert_eq!(format!("{fmt:.4}"), "0.3529");
+/// assert_eq!(format!("{fmt:#}"), "0.35 (12/34)");
+/// assert_eq!(format!("{:.0}", fmt_tools::fmt_ratio(7, 2)), "4");
+/// ```
+#[must_use]
+pub const fn fmt_ratio(numerator: u64, denominator: u64) -> FmtRatio {
+    FmtRatio::new(numerator, denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_fmt_percent() {
+        let test_cases = [
+            (0, 10, "0%", "0.00%"),
+            (1, 3, "33%", "33.33%"),
+            (2, 3, "67%", "66.67%"),
+            (1, 8, "13%", "12.50%"),
+            (1, 200, "1%", "0.50%"),
+            (10, 10, "100%", "100.00%"),
+            (25, 10, "250%", "250.00%"),
+            (1, 0, "n/a", "n/a"),
+            (u64::MAX, 1, "1844674407370955161500%", "1844674407370955161500.00%"),
+        ];
+
+        for (numerator, denominator, expected, expected_precise) in test_cases {
+            let fmt = super::fmt_percent(numerator, denominator);
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{fmt:.2}"), expected_precise);
+            assert_eq!(
+                std::format!("{fmt:#}"),
+                std::format!("{expected} ({numerator}/{denominator})")
+            );
+        }
+
+        let max = super::fmt_percent(u64::MAX, 1);
+
+        assert_eq!(
+            std::format!("{max:#.16}"),
+            "1844674407370955161500.0000000000000000% (18446744073709551615/1)"
+        );
+        assert_eq!(std::format!("{max:.100}"), std::format!("{max:.16}"));
+    }
+
+    #[test]
+    fn test_fmt_ratio() {
+        let test_cases = [
+            (0, 10, "0.00", "0"),
+            (1, 3, "0.33", "0"),
+            (2, 3, "0.67", "1"),
+            (1, 8, "0.13", "0"),
+            (7, 2, "3.50", "4"),
+            (1, 0, "n/a", "n/a"),
+        ];
+
+        for (numerator, denominator, expected, expected_integer) in test_cases {
+            let fmt = super::fmt_ratio(numerator, denominator);
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{fmt:.0}"), expected_integer);
+        }
+    }
+
+    #[test]
+    fn test_fmt_percent_padding() {
+        let fmt = super::fmt_percent(1, 3);
+
+        assert_eq!(std::format!("{fmt:6}"), "   33%");
+        assert_eq!(std::format!("{fmt:<6}|"), "33%   |");
+        assert_eq!(std::format!("{fmt:+}"), "+33%");
+        assert_eq!(std::format!("{fmt:07.1}"), "0033.3%");
+        assert_eq!(std::format!("{fmt:>#14.1}"), "   33.3% (1/3)");
+        assert_eq!(std::format!("{fmt:^9}|"), "   33%   |");
+        assert_eq!(std::format!("{fmt:*<7}"), "33%****");
+        assert_eq!(std::format!("{fmt:+07.1}"), "+033.3%");
+        assert_eq!(std::format!("{fmt:#014.1}"), "00033.3% (1/3)");
+        assert_eq!(std::format!("{fmt:+#011}"), "+033% (1/3)");
+        assert_eq!(std::format!("{fmt:#05}"), "33% (1/3)");
+
+        let not_available = super::fmt_percent(1, 0);
+
+        assert_eq!(std::format!("{not_available:05}"), "  n/a");
+        assert_eq!(std::format!("{not_available:+05}"), "  n/a");
+        assert_eq!(std::format!("{not_available:<5}|"), "n/a  |");
+        assert_eq!(std::format!("{not_available:#011}"), "  n/a (1/0)");
+
+        let ratio = super::fmt_ratio(1, 8);
+
+        assert_eq!(std::format!("{ratio:06}"), "000.13");
+        assert_eq!(std::format!("{ratio:#012.1}"), "0000.1 (1/8)");
+    }
+}
@@ -0,0 +1,289 @@
+use crate::fmt_separated_list::Inner;
+use crate::fmt_separated_map::{self, MapSeparators};
+use crate::utilities;
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+/// A set of ASCII characters that should be percent-encoded. Non-ASCII characters are always percent-encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AsciiSet {
+    mask: u128,
+}
+
+impl AsciiSet {
+    /// A set that contains no characters.
+    pub const EMPTY: Self = Self { mask: 0 };
+
+    /// A set that contains all ASCII control characters.
+    pub const CONTROLS: Self = Self {
+        mask: 0xffff_ffff | (1 << 0x7f),
+    };
+
+    /// A set that contains all ASCII characters except ASCII letters and digits.
+    pub const NON_ALPHANUMERIC: Self = Self {
+        mask: !((0x3ff << b'0') | (0x3ff_ffff << b'A') | (0x3ff_ffff << b'a')),
+    };
+
+    /// A set that contains all ASCII characters except the unreserved characters defined by
+    /// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-2.3), which are ASCII letters, digits, `-`, `.`, `_`
+    /// and `~`.
+    pub const NON_UNRESERVED: Self = Self::NON_ALPHANUMERIC
+        .remove(b'-')
+        .remove(b'.')
+        .remove(b'_')
+        .remove(b'~');
+
+    /// Returns a new set with `byte` added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` is not an ASCII character.
+    #[must_use]
+    pub const fn add(self, byte: u8) -> Self {
+        assert!(byte.is_ascii(), "`byte` must be an ASCII character");
+
+        Self {
+            mask: self.mask | (1 << byte),
+        }
+    }
+
+    /// Returns a new set with `byte` removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` is not an ASCII character.
+    #[must_use]
+    pub const fn remove(self, byte: u8) -> Self {
+        assert!(byte.is_ascii(), "`byte` must be an ASCII character");
+
+        Self {
+            mask: self.mask & !(1 << byte),
+        }
+    }
+
+    /// Returns a new set that contains characters from both sets.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            mask: self.mask | other.mask,
+        }
+    }
+
+    /// Checks whether `byte` is in the set. Always returns `false` for non-ASCII bytes.
+    #[must_use]
+    pub const fn contains(self, byte: u8) -> bool {
+        byte.is_ascii() && self.mask & (1 << byte) != 0
+    }
+}
+
+fn write_percent_encoded(args: Arguments, set: AsciiSet, f: &mut Formatter) -> fmt::Result {
+    utilities::try_for_each_char(args, |c| {
+        if c.is_ascii() && !set.contains(c as u8) {
+            f.write_char(c)
+        } else {
+            c.encode_utf8(&mut [0; 4])
+                .bytes()
+                .try_for_each(|byte| f.write_fmt(format_args!("%{byte:02X}")))
+        }
+    })
+}
+
+/// [`Debug`] or [`Display`] a [`Display`] object with its output percent-encoded.
+pub struct FmtPercentEncoded<T>
+where
+    T: ?Sized,
+{
+    set: AsciiSet,
+    value: T,
+}
+
+impl<T> FmtPercentEncoded<T> {
+    const fn new(value: T, set: AsciiSet) -> Self {
+        Self { set, value }
+    }
+}
+
+impl<T> Debug for FmtPercentEncoded<T>
+where
+    T: Display + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_percent_encoded(format_args!("{}", &&self.value), self.set, f)
+    }
+}
+
+impl<T> Display for FmtPercentEncoded<T>
+where
+    T: Display + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] a list of `(Display, Display)` objects as a URL query string.
+pub struct FmtQuery<F>
+where
+    F: ?Sized,
+{
+    inner: Inner<F, MapSeparators<&'static str>>,
+}
+
+impl<F> FmtQuery<F> {
+    const fn new(values_fn: F) -> Self {
+        Self {
+            inner: Inner::new(values_fn, MapSeparators::new("", "=", "&", "")),
+        }
+    }
+}
+
+impl<F, I, K, V> Debug for FmtQuery<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_separated_map::fmt_map(
+            &self.inner,
+            f,
+            |key, f| write_percent_encoded(format_args!("{key}"), AsciiSet::NON_UNRESERVED, f),
+            |value, f| write_percent_encoded(format_args!("{value}"), AsciiSet::NON_UNRESERVED, f),
+            |separator, f| f.write_str(separator),
+        )
+    }
+}
+
+impl<F, I, K, V> Display for FmtQuery<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object with characters in `set` and all non-ASCII
+/// characters percent-encoded.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::AsciiSet;
+///
+/// let fmt = fmt_tools::fmt_percent_encoded("a b/ü", AsciiSet::NON_UNRESERVED);
+///
+/// assert_eq!(format!("{fmt:?}"), "a%20b%2F%C3%BC");
+/// assert_eq!(format!("{fmt}"), "a%20b%2F%C3%BC");
+///
+/// let user = fmt_tools::fmt_percent_encoded("John Doe", AsciiSet::CONTROLS.add(b' '));
+/// let path = fmt_tools::format_args_owned!("/users/{}", user);
+///
+/// assert_eq!(path.to_string(), "/users/John%20Doe");
+/// ```
+pub const fn fmt_percent_encoded<T>(value: T, set: AsciiSet) -> FmtPercentEncoded<T>
+where
+    T: Display,
+{
+    FmtPercentEncoded::new(value, set)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of `(Display, Display)` objects as a URL query string.
+/// Keys and values are percent-encoded with [`AsciiSet::NON_UNRESERVED`].
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_query(|| [("a", "1"), ("b", "x y")]);
+///
+/// assert_eq!(format!("{fmt:?}"), "a=1&b=x%20y");
+/// assert_eq!(format!("{fmt}"), "a=1&b=x%20y");
+/// ```
+pub const fn fmt_query<F, I, K, V>(values_fn: F) -> FmtQuery<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    FmtQuery::new(values_fn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsciiSet, FmtPercentEncoded, FmtQuery};
+    use core::fmt::Display;
+
+    #[test]
+    fn test_ascii_set() {
+        for byte in 0..=u8::MAX {
+            let c = char::from(byte);
+
+            assert!(!AsciiSet::EMPTY.contains(byte));
+            assert_eq!(AsciiSet::CONTROLS.contains(byte), c.is_ascii_control());
+            assert_eq!(
+                AsciiSet::NON_ALPHANUMERIC.contains(byte),
+                c.is_ascii() && !c.is_ascii_alphanumeric()
+            );
+            assert_eq!(
+                AsciiSet::NON_UNRESERVED.contains(byte),
+                c.is_ascii() && !c.is_ascii_alphanumeric() && !"-._~".contains(c)
+            );
+        }
+
+        assert!(AsciiSet::EMPTY.add(b'a').contains(b'a'));
+        assert!(!AsciiSet::CONTROLS.remove(b'\n').contains(b'\n'));
+        assert_eq!(
+            AsciiSet::EMPTY.add(b'a').union(AsciiSet::EMPTY.add(b'b')),
+            AsciiSet::EMPTY.add(b'b').add(b'a')
+        );
+    }
+
+    #[test]
+    fn test_fmt_percent_encoded() {
+        let test_cases = [
+            ("", AsciiSet::NON_ALPHANUMERIC, ""),
+            ("abc", AsciiSet::NON_ALPHANUMERIC, "abc"),
+            ("a-b_c", AsciiSet::NON_ALPHANUMERIC, "a%2Db%5Fc"),
+            ("a-b_c", AsciiSet::NON_UNRESERVED, "a-b_c"),
+            ("a b\n", AsciiSet::CONTROLS, "a b%0A"),
+            ("a b\n", AsciiSet::EMPTY, "a b\n"),
+            ("100%", AsciiSet::EMPTY.add(b'%'), "100%25"),
+            ("日本", AsciiSet::EMPTY, "%E6%97%A5%E6%9C%AC"),
+        ];
+
+        for (value, set, expected) in test_cases {
+            let fmt = super::fmt_percent_encoded(value, set);
+            let unsized_fmt: &FmtPercentEncoded<dyn Display> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_fmt_query() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[(&str, &str)], ""),
+            (&[("a", "1")], "a=1"),
+            (&[("a", "1"), ("b", "x y")], "a=1&b=x%20y"),
+            (&[("a&b", "c=d"), ("", "")], "a%26b=c%3Dd&="),
+        ];
+
+        for (values, expected) in test_cases {
+            let fmt = super::fmt_query(|| values.iter().copied());
+            let unsized_fmt: &FmtQuery<dyn Fn() -> _> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+}
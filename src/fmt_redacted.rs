@@ -0,0 +1,276 @@
+use crate::utilities;
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+/// Specifies how [`FmtRedacted`] hides its value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedactionPolicy {
+    /// Writes `***`.
+    Hidden,
+    /// Writes the specified number of `*` characters, regardless of the value.
+    Mask(usize),
+    /// Writes the length of the value in bytes, like `<redacted 32 bytes>`.
+    LengthHint,
+    /// Writes `***` followed by the specified number of trailing characters of the value. If the value does not have
+    /// more characters than that, only `***` is written.
+    RevealLast(usize),
+}
+
+/// The measurements of the text that a policy applies to.
+struct Content {
+    chars: usize,
+    bytes: usize,
+    quoted: bool,
+}
+
+impl Content {
+    /// Measures the output of `args`. If `strip_quotes` is `true` and the output is delimited by matching `"` or `'`
+    /// characters, like [`Debug`] output of strings and characters, the delimiters are excluded, so that the [`Debug`]
+    /// output reveals the same characters as the [`Display`] output.
+    fn measure(args: Arguments, strip_quotes: bool) -> Result<Self, fmt::Error> {
+        let mut chars = 0;
+        let mut bytes = 0;
+        let mut first = None;
+        let mut last = None;
+
+        utilities::try_for_each_char(args, |c| {
+            chars += 1;
+            bytes += c.len_utf8();
+            first = first.or(Some(c));
+            last = Some(c);
+
+            Ok(())
+        })?;
+
+        let quoted = strip_quotes && chars >= 2 && first == last && matches!(first, Some('"' | '\''));
+
+        Ok(if quoted {
+            Self {
+                chars: chars - 2,
+                bytes: bytes - 2,
+                quoted,
+            }
+        } else {
+            Self { chars, bytes, quoted }
+        })
+    }
+}
+
+impl RedactionPolicy {
+    fn redact(self, args: Arguments, strip_quotes: bool, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Hidden => f.write_str("***"),
+            Self::Mask(length) => (0..length).try_for_each(|_| f.write_char('*')),
+            Self::LengthHint => {
+                let content = Content::measure(args, strip_quotes)?;
+
+                f.write_fmt(format_args!("<redacted {} bytes>", content.bytes))
+            }
+            Self::RevealLast(count) => {
+                let content = Content::measure(args, strip_quotes)?;
+
+                f.write_str("***")?;
+
+                if content.chars > count {
+                    let start = content.chars - count + usize::from(content.quoted);
+                    let end = content.chars + usize::from(content.quoted);
+                    let mut index = 0;
+
+                    utilities::try_for_each_char(args, |c| {
+                        let result = if (start..end).contains(&index) {
+                            f.write_char(c)
+                        } else {
+                            Ok(())
+                        };
+
+                        index += 1;
+
+                        result
+                    })?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// [`Debug`] or [`Display`] a value with its content redacted.
+///
+/// Policies that depend on the content apply to the [`Display`] output, or to the [`Debug`] output without the
+/// delimiting quotes of strings and characters.
+///
+/// If the crate is built with `--cfg fmt_tools_disable_redaction` in `RUSTFLAGS`, the value is formatted as is, which
+/// could be useful in tests. This is not a Cargo feature, so that no dependency can disable redaction for the whole
+/// dependency graph.
+pub struct FmtRedacted<T>
+where
+    T: ?Sized,
+{
+    policy: RedactionPolicy,
+    value: T,
+}
+
+impl<T> FmtRedacted<T> {
+    const fn new(value: T, policy: RedactionPolicy) -> Self {
+        Self { policy, value }
+    }
+}
+
+impl<T> Debug for FmtRedacted<T>
+where
+    T: Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if cfg!(fmt_tools_disable_redaction) {
+            self.value.fmt(f)
+        } else {
+            self.policy.redact(format_args!("{:?}", &&self.value), true, f)
+        }
+    }
+}
+
+impl<T> Display for FmtRedacted<T>
+where
+    T: Display + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if cfg!(fmt_tools_disable_redaction) {
+            self.value.fmt(f)
+        } else {
+            self.policy.redact(format_args!("{}", &&self.value), false, f)
+        }
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a value as `***`.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_redacted("password");
+///
+/// # #[cfg(not(fmt_tools_disable_redaction))]
+/// # {
+/// assert_eq!(format!("{fmt:?}"), "***");
+/// assert_eq!(format!("{fmt}"), "***");
+/// # }
+/// ```
+pub const fn fmt_redacted<T>(value: T) -> FmtRedacted<T> {
+    FmtRedacted::new(value, RedactionPolicy::Hidden)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a value redacted according to the specified policy.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::RedactionPolicy;
+///
+/// let mask = fmt_tools::fmt_redacted_with("password", RedactionPolicy::Mask(4));
+/// let length_hint = fmt_tools::fmt_redacted_with("password", RedactionPolicy::LengthHint);
+/// let reveal_last = fmt_tools::fmt_redacted_with("password", RedactionPolicy::RevealLast(4));
+///
+/// # #[cfg(not(fmt_tools_disable_redaction))]
+/// # {
+/// assert_eq!(format!("{mask}"), "****");
+/// assert_eq!(format!("{length_hint}"), "<redacted 8 bytes>");
+/// assert_eq!(format!("{length_hint:?}"), "<redacted 8 bytes>");
+/// assert_eq!(format!("{reveal_last:?}"), "***word");
+/// assert_eq!(format!("{reveal_last}"), "***word");
+/// # }
+/// ```
+pub const fn fmt_redacted_with<T>(value: T, policy: RedactionPolicy) -> FmtRedacted<T> {
+    FmtRedacted::new(value, policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FmtRedacted, RedactionPolicy};
+    use core::fmt::Display;
+
+    #[cfg(not(fmt_tools_disable_redaction))]
+    #[test]
+    fn test_fmt_redacted() {
+        let fmt = super::fmt_redacted("secret");
+        let unsized_fmt: &FmtRedacted<dyn Display> = &fmt;
+
+        assert_eq!(std::format!("{fmt:?}"), "***");
+        assert_eq!(std::format!("{fmt}"), "***");
+        assert_eq!(std::format!("{unsized_fmt}"), "***");
+    }
+
+    #[cfg(not(fmt_tools_disable_redaction))]
+    #[test]
+    fn test_fmt_redacted_with() {
+        let test_cases = [
+            ("", RedactionPolicy::Hidden, "***", "***"),
+            ("secret", RedactionPolicy::Hidden, "***", "***"),
+            ("", RedactionPolicy::Mask(0), "", ""),
+            ("secret", RedactionPolicy::Mask(3), "***", "***"),
+            (
+                "",
+                RedactionPolicy::LengthHint,
+                "<redacted 0 bytes>",
+                "<redacted 0 bytes>",
+            ),
+            (
+                "秘密",
+                RedactionPolicy::LengthHint,
+                "<redacted 6 bytes>",
+                "<redacted 6 bytes>",
+            ),
+            ("secret", RedactionPolicy::RevealLast(0), "***", "***"),
+            ("secret", RedactionPolicy::RevealLast(2), "***et", "***et"),
+            ("secret", RedactionPolicy::RevealLast(5), "***ecret", "***ecret"),
+            ("secret", RedactionPolicy::RevealLast(6), "***", "***"),
+            ("secret", RedactionPolicy::RevealLast(8), "***", "***"),
+            ("秘密です", RedactionPolicy::RevealLast(2), "***です", "***です"),
+            ("\"", RedactionPolicy::RevealLast(0), "***", "***"),
+            (
+                "a\"",
+                RedactionPolicy::LengthHint,
+                "<redacted 2 bytes>",
+                "<redacted 3 bytes>",
+            ),
+        ];
+
+        for (value, policy, expected, expected_debug) in test_cases {
+            let fmt = super::fmt_redacted_with(value, policy);
+
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{fmt:?}"), expected_debug);
+        }
+
+        let char_fmt = super::fmt_redacted_with('x', RedactionPolicy::LengthHint);
+        let integer_fmt = super::fmt_redacted_with(12345, RedactionPolicy::RevealLast(2));
+        let option_fmt = super::fmt_redacted_with(Some("ab"), RedactionPolicy::RevealLast(3));
+
+        assert_eq!(std::format!("{char_fmt:?}"), "<redacted 1 bytes>");
+        assert_eq!(std::format!("{integer_fmt}"), "***45");
+        assert_eq!(std::format!("{integer_fmt:?}"), "***45");
+        assert_eq!(std::format!("{option_fmt:?}"), "***b\")");
+    }
+
+    #[cfg(fmt_tools_disable_redaction)]
+    #[test]
+    fn test_fmt_redacted_disabled() {
+        let fmt = super::fmt_redacted("secret");
+        let unsized_fmt: &FmtRedacted<dyn Display> = &fmt;
+
+        assert_eq!(std::format!("{fmt:?}"), "\"secret\"");
+        assert_eq!(std::format!("{fmt}"), "secret");
+        assert_eq!(std::format!("{unsized_fmt}"), "secret");
+
+        for policy in [
+            RedactionPolicy::Hidden,
+            RedactionPolicy::Mask(3),
+            RedactionPolicy::LengthHint,
+            RedactionPolicy::RevealLast(2),
+        ] {
+            let fmt = super::fmt_redacted_with("secret", policy);
+
+            assert_eq!(std::format!("{fmt}"), "secret");
+            assert_eq!(std::format!("{fmt:?}"), "\"secret\"");
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// [`Debug`] or [`Display`] a [`Display`] object repeatedly, with a separator that implements [`Display`].
+///
+/// If a width is specified in the format string, it is used as the repetition count instead of the stored count, for
+/// example, `{:10}` repeats the value ten times.
+pub struct FmtRepeat<T, S>
+where
+    T: ?Sized,
+{
+    count: usize,
+    separator: S,
+    value: T,
+}
+
+impl<T, S> FmtRepeat<T, S> {
+    const fn new(value: T, count: usize, separator: S) -> Self {
+        Self {
+            count,
+            separator,
+            value,
+        }
+    }
+}
+
+impl<T, S> Debug for FmtRepeat<T, S>
+where
+    T: Display + ?Sized,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let count = f.width().unwrap_or(self.count);
+
+        // Use `write_fmt` so that the width flag will not be applied to the value and the separator.
+        for i in 0..count {
+            if i != 0 {
+                f.write_fmt(format_args!("{}", self.separator))?;
+            }
+
+            f.write_fmt(format_args!("{}", &&self.value))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, S> Display for FmtRepeat<T, S>
+where
+    T: Display + ?Sized,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object `count` times.
+///
+/// If a width is specified in the format string, it is used as the repetition count instead.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_repeat('-', 3);
+///
+/// assert_eq!(format!("{fmt:?}"), "---");
+/// assert_eq!(format!("{fmt}"), "---");
+/// assert_eq!(format!("{fmt:5}"), "-----");
+/// ```
+pub const fn fmt_repeat<T>(value: T, count: usize) -> FmtRepeat<T, &'static str>
+where
+    T: Display,
+{
+    FmtRepeat::new(value, count, "")
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object `count` times, with specified separator that
+/// implements [`Display`].
+///
+/// If a width is specified in the format string, it is used as the repetition count instead.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_separated_repeat("ab", 3, ' ');
+///
+/// assert_eq!(format!("{fmt:?}"), "ab ab ab");
+/// assert_eq!(format!("{fmt}"), "ab ab ab");
+/// assert_eq!(format!("{fmt:2}"), "ab ab");
+/// ```
+pub const fn fmt_separated_repeat<T, S>(value: T, count: usize, separator: S) -> FmtRepeat<T, S>
+where
+    T: Display,
+    S: Display,
+{
+    FmtRepeat::new(value, count, separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtRepeat;
+    use core::fmt::Display;
+
+    #[test]
+    fn test_fmt_repeat() {
+        let test_cases = [(0, ""), (1, "=="), (2, "===="), (3, "======")];
+
+        for (count, expected) in test_cases {
+            let fmt = super::fmt_repeat("==", count);
+            let unsized_fmt: &FmtRepeat<dyn Display, &str> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+            assert_eq!(
+                std::format!("{:width$}", super::fmt_repeat("==", 7), width = count),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_fmt_separated_repeat() {
+        let test_cases = [(0, ""), (1, "a"), (2, "a, a"), (3, "a, a, a")];
+
+        for (count, expected) in test_cases {
+            let fmt = super::fmt_separated_repeat('a', count, ", ");
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(
+                std::format!("{:width$}", super::fmt_separated_repeat('a', 7, ", "), width = count),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_fmt_repeat_ignores_flags() {
+        assert_eq!(
+            std::format!("{:.1}", super::fmt_separated_repeat("ab", 2, "cd")),
+            "abcdab"
+        );
+        assert_eq!(
+            std::format!("{:>3.1}", super::fmt_separated_repeat("ab", 9, "cd")),
+            "abcdabcdab"
+        );
+    }
+}
@@ -1,6 +1,8 @@
+#[cfg(feature = "defmt")]
+use core::convert::Infallible;
 use core::fmt::{self, Debug, Display, Formatter};
 
-struct Inner<F, S>
+pub(crate) struct Inner<F, S>
 where
     F: ?Sized,
 {
@@ -12,14 +14,18 @@ impl<F, S> Inner<F, S>
 where
     F: ?Sized,
 {
-    const fn new(values_fn: F, separator: S) -> Self
+    pub(crate) const fn new(values_fn: F, separator: S) -> Self
     where
         F: Sized,
     {
         Self { separator, values_fn }
     }
 
-    fn fmt_with<I>(
+    pub(crate) const fn separator(&self) -> &S {
+        &self.separator
+    }
+
+    pub(crate) fn fmt_with<I>(
         &self,
         f: &mut Formatter,
         value_writer: impl FnOnce(&I::Item, &mut Formatter) -> fmt::Result + Copy,
@@ -29,19 +35,107 @@ where
         F: Fn() -> I,
         I: IntoIterator,
     {
-        let mut iter = (self.values_fn)().into_iter();
+        fmt_separated((self.values_fn)(), f, value_writer, |f| {
+            separator_writer(&self.separator, f)
+        })
+    }
 
-        if let Some(first) = iter.next() {
-            value_writer(&first, f)?;
+    #[cfg(feature = "defmt")]
+    pub(crate) fn defmt_with<I>(
+        &self,
+        fmt: defmt::Formatter,
+        value_writer: impl Fn(&I::Item, defmt::Formatter),
+        separator_writer: impl Fn(&S, defmt::Formatter),
+    ) where
+        F: Fn() -> I,
+        I: IntoIterator,
+    {
+        defmt_separated((self.values_fn)(), fmt, value_writer, |fmt| {
+            separator_writer(&self.separator, fmt);
+        });
+    }
 
-            for item in iter {
-                separator_writer(&self.separator, f)?;
-                value_writer(&item, f)?;
-            }
-        }
+    #[cfg(feature = "ufmt")]
+    pub(crate) fn ufmt_with<I, W>(
+        &self,
+        f: &mut ufmt::Formatter<W>,
+        value_writer: impl Fn(&I::Item, &mut ufmt::Formatter<W>) -> Result<(), W::Error>,
+        separator_writer: impl Fn(&S, &mut ufmt::Formatter<W>) -> Result<(), W::Error>,
+    ) -> Result<(), W::Error>
+    where
+        F: Fn() -> I,
+        I: IntoIterator,
+        W: ufmt::uWrite + ?Sized,
+    {
+        try_for_each_separated((self.values_fn)(), f, value_writer, |f| {
+            separator_writer(&self.separator, f)
+        })
+    }
+}
 
-        Ok(())
+/// Calls `value_writer` on each item of `values`, and `separator_writer` between adjacent items. `context` is passed
+/// to both of them, so that they can share a formatter.
+fn try_for_each_separated<I, C, E>(
+    values: I,
+    context: &mut C,
+    mut value_writer: impl FnMut(&I::Item, &mut C) -> Result<(), E>,
+    mut separator_writer: impl FnMut(&mut C) -> Result<(), E>,
+) -> Result<(), E>
+where
+    I: IntoIterator,
+{
+    let mut iter = values.into_iter();
+
+    if let Some(first) = iter.next() {
+        value_writer(&first, context)?;
+
+        for item in iter {
+            separator_writer(context)?;
+            value_writer(&item, context)?;
+        }
     }
+
+    Ok(())
+}
+
+/// Writes each item of `values` with `value_writer`, calling `separator_writer` between adjacent items.
+pub(crate) fn fmt_separated<I>(
+    values: I,
+    f: &mut Formatter,
+    value_writer: impl FnOnce(&I::Item, &mut Formatter) -> fmt::Result + Copy,
+    separator_writer: impl FnOnce(&mut Formatter) -> fmt::Result + Copy,
+) -> fmt::Result
+where
+    I: IntoIterator,
+{
+    try_for_each_separated(values, f, |item, f| value_writer(item, f), |f| separator_writer(f))
+}
+
+/// Like [`fmt_separated`], but writes to a [`defmt::Formatter`].
+#[cfg(feature = "defmt")]
+pub(crate) fn defmt_separated<I>(
+    values: I,
+    fmt: defmt::Formatter,
+    value_writer: impl Fn(&I::Item, defmt::Formatter),
+    separator_writer: impl Fn(defmt::Formatter),
+) where
+    I: IntoIterator,
+{
+    try_for_each_separated(
+        values,
+        &mut (),
+        |item, ()| {
+            value_writer(item, fmt);
+
+            Ok::<_, Infallible>(())
+        },
+        |()| {
+            separator_writer(fmt);
+
+            Ok(())
+        },
+    )
+    .unwrap_or_else(|never| match never {});
 }
 
 /// [`Debug`] or [`Display`] a list of [`Debug`] objects with a separator that implements [`Debug`].
@@ -87,6 +181,57 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<F, S, I> defmt::Format for FmtSeparatedDebugList<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: defmt::Format,
+    S: defmt::Format,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        self.inner.defmt_with(
+            fmt,
+            |item, fmt| defmt::write!(fmt, "{}", item),
+            |separator, fmt| {
+                defmt::write!(fmt, "{}", separator);
+            },
+        );
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, S, I> ufmt::uDebug for FmtSeparatedDebugList<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDebug,
+    S: ufmt::uDebug,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        self.inner.ufmt_with(f, ufmt::uDebug::fmt, ufmt::uDebug::fmt)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, S, I> ufmt::uDisplay for FmtSeparatedDebugList<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDebug,
+    S: ufmt::uDebug,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDebug::fmt(self, f)
+    }
+}
+
 /// [`Debug`] or [`Display`] a list of [`Display`] objects with a separator that implements [`Display`].
 pub struct FmtSeparatedDisplayList<F, S>
 where
@@ -130,6 +275,98 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<F, S, I> defmt::Format for FmtSeparatedDisplayList<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: Display,
+    S: Display,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        self.inner.defmt_with(
+            fmt,
+            |item, fmt| defmt::write!(fmt, "{}", defmt::Display2Format(item)),
+            |separator, fmt| defmt::write!(fmt, "{}", defmt::Display2Format(separator)),
+        );
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, S, I> ufmt::uDisplay for FmtSeparatedDisplayList<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDisplay,
+    S: ufmt::uDisplay,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        self.inner.ufmt_with(f, ufmt::uDisplay::fmt, ufmt::uDisplay::fmt)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, S, I> ufmt::uDebug for FmtSeparatedDisplayList<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDisplay,
+    S: ufmt::uDisplay,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDisplay::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] a list of objects with a separator that implements [`Display`], formatting each object
+/// with a function object.
+pub struct FmtSeparatedListWith<F, S, W>
+where
+    F: ?Sized,
+{
+    value_writer: W,
+    inner: Inner<F, S>,
+}
+
+impl<F, S, W> FmtSeparatedListWith<F, S, W> {
+    const fn new(values_fn: F, separator: S, value_writer: W) -> Self {
+        Self {
+            value_writer,
+            inner: Inner::new(values_fn, separator),
+        }
+    }
+}
+
+impl<F, S, W, I> Debug for FmtSeparatedListWith<F, S, W>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    S: Display,
+    W: Fn(&I::Item, &mut Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.inner.fmt_with(f, &self.value_writer, S::fmt)
+    }
+}
+
+impl<F, S, W, I> Display for FmtSeparatedListWith<F, S, W>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    S: Display,
+    W: Fn(&I::Item, &mut Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Creates an object that [`Debug`] or [`Display`] a list of [`Debug`] objects with specified separator that implements
 /// [`Debug`].
 ///
@@ -172,9 +409,34 @@ where
     FmtSeparatedDisplayList::new(values_fn, separator)
 }
 
+/// Creates an object that [`Debug`] or [`Display`] a list of objects with specified separator that implements
+/// [`Display`], formatting each object with the provided function object.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_separated_list_with(|| 10..13, ", ", |value, f| write!(f, "{value:#x}"));
+///
+/// assert_eq!(format!("{fmt:?}"), "0xa, 0xb, 0xc");
+/// assert_eq!(format!("{fmt}"), "0xa, 0xb, 0xc");
+/// ```
+pub const fn fmt_separated_list_with<F, S, W, I>(
+    values_fn: F,
+    separator: S,
+    value_writer: W,
+) -> FmtSeparatedListWith<F, S, W>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    S: Display,
+    W: Fn(&I::Item, &mut Formatter) -> fmt::Result,
+{
+    FmtSeparatedListWith::new(values_fn, separator, value_writer)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{FmtSeparatedDebugList, FmtSeparatedDisplayList};
+    use super::{FmtSeparatedDebugList, FmtSeparatedDisplayList, FmtSeparatedListWith};
     use core::fmt::{self, Display, Formatter};
 
     #[test]
@@ -240,4 +502,88 @@ mod tests {
             assert_eq!(std::format!("{unsized_fmt}"), expected);
         }
     }
+
+    #[test]
+    fn test_separated_list_with() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[u32], ""),
+            (&[1], "<1>"),
+            (&[1, 2], "<1>, <2>"),
+            (&[1, 2, 3], "<1>, <2>, <3>"),
+        ];
+
+        for (values, expected) in test_cases {
+            let fmt =
+                super::fmt_separated_list_with(|| values, ", ", |value, f| f.write_fmt(format_args!("<{value}>")));
+            let unsized_fmt: &FmtSeparatedListWith<dyn Fn() -> &'static [u32], &str, _> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_ufmt_fmt_separated_list() {
+        let debug_list = super::fmt_separated_debug_list(|| [Some(1), None], 0);
+        let display_list = super::fmt_separated_display_list(|| ["a", "b", "c"], " | ");
+        let empty_list = super::fmt_separated_display_list(|| [0_u8; 0], ", ");
+        let mut buffer = std::string::String::new();
+
+        ufmt::uwrite!(
+            buffer,
+            "{:?} {} {}{}",
+            debug_list,
+            display_list,
+            display_list,
+            empty_list
+        )
+        .unwrap();
+
+        assert_eq!(buffer, "Some(1)0None a | b | c a | b | c");
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_fmt_separated_list() {
+        fn assert_format<T>(_: &T)
+        where
+            T: defmt::Format + ?Sized,
+        {
+        }
+
+        let debug_list = super::fmt_separated_debug_list(|| [1, 2], 0);
+        let display_list = super::fmt_separated_display_list(|| ["a", "b"], ", ");
+        let unsized_debug_list: &FmtSeparatedDebugList<dyn Fn() -> [i32; 2], i32> = &debug_list;
+        let unsized_display_list: &FmtSeparatedDisplayList<dyn Fn() -> [&'static str; 2], &str> = &display_list;
+
+        assert_format(&debug_list);
+        assert_format(&display_list);
+        assert_format(unsized_debug_list);
+        assert_format(unsized_display_list);
+
+        let (index, bytes) = crate::utilities::defmt_encode(&debug_list);
+        let istr = |offset: u16| (index + offset).to_le_bytes();
+
+        assert_eq!(
+            bytes,
+            [
+                &istr(0)[..], // Format sequence.
+                &istr(1),     // "{}"
+                &istr(2),     // "{=i32}"
+                &1_i32.to_le_bytes(),
+                &istr(3), // "{}"
+                &istr(4), // "{=i32}"
+                &0_i32.to_le_bytes(),
+                &istr(5), // "{}"
+                &istr(6), // "{=i32}"
+                &2_i32.to_le_bytes(),
+                &[0, 0], // End of the format sequence.
+            ]
+            .concat()
+        );
+    }
 }
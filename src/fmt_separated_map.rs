@@ -0,0 +1,285 @@
+use crate::fmt_separated_list::Inner;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// Delimiters used by [`FmtSeparatedDebugMap`] and [`FmtSeparatedDisplayMap`].
+#[derive(Clone, Copy)]
+pub struct MapSeparators<S> {
+    opening: S,
+    key_value: S,
+    entry: S,
+    closing: S,
+}
+
+impl<S> MapSeparators<S> {
+    /// Creates a new `MapSeparators` object. `opening` and `closing` are written before and after all entries,
+    /// `key_value` is written between the key and the value of each entry, and `entry` is written between entries.
+    pub const fn new(opening: S, key_value: S, entry: S, closing: S) -> Self {
+        Self {
+            opening,
+            key_value,
+            entry,
+            closing,
+        }
+    }
+}
+
+pub(crate) fn fmt_map<F, S, I, K, V>(
+    inner: &Inner<F, MapSeparators<S>>,
+    f: &mut Formatter,
+    key_writer: impl FnOnce(&K, &mut Formatter) -> fmt::Result + Copy,
+    value_writer: impl FnOnce(&V, &mut Formatter) -> fmt::Result + Copy,
+    separator_writer: impl FnOnce(&S, &mut Formatter) -> fmt::Result + Copy,
+) -> fmt::Result
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let separators = inner.separator();
+
+    separator_writer(&separators.opening, f)?;
+
+    inner.fmt_with(
+        f,
+        |(key, value), f| {
+            key_writer(key, f)?;
+            separator_writer(&separators.key_value, f)?;
+            value_writer(value, f)
+        },
+        |separators, f| separator_writer(&separators.entry, f),
+    )?;
+
+    separator_writer(&separators.closing, f)
+}
+
+/// [`Debug`] or [`Display`] a list of `(Debug, Debug)` objects as a map with custom separators that implement
+/// [`Debug`].
+pub struct FmtSeparatedDebugMap<F, S>
+where
+    F: ?Sized,
+{
+    inner: Inner<F, MapSeparators<S>>,
+}
+
+impl<F, S> FmtSeparatedDebugMap<F, S> {
+    const fn new(values_fn: F, separators: MapSeparators<S>) -> Self {
+        Self {
+            inner: Inner::new(values_fn, separators),
+        }
+    }
+}
+
+impl<F, S, I, K, V> Debug for FmtSeparatedDebugMap<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Debug,
+    V: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_map(&self.inner, f, K::fmt, V::fmt, S::fmt)
+    }
+}
+
+impl<F, S, I, K, V> Display for FmtSeparatedDebugMap<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Debug,
+    V: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] a list of `(Display, Display)` objects as a map with custom separators that implement
+/// [`Display`].
+pub struct FmtSeparatedDisplayMap<F, S>
+where
+    F: ?Sized,
+{
+    inner: Inner<F, MapSeparators<S>>,
+}
+
+impl<F, S> FmtSeparatedDisplayMap<F, S> {
+    const fn new(values_fn: F, separators: MapSeparators<S>) -> Self {
+        Self {
+            inner: Inner::new(values_fn, separators),
+        }
+    }
+}
+
+impl<F, S, I, K, V> Debug for FmtSeparatedDisplayMap<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_map(&self.inner, f, K::fmt, V::fmt, S::fmt)
+    }
+}
+
+impl<F, S, I, K, V> Display for FmtSeparatedDisplayMap<F, S>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of `(Debug, Debug)` objects as a map with specified
+/// separators that implement [`Debug`].
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::MapSeparators;
+///
+/// let fmt = fmt_tools::fmt_separated_debug_map(|| ('a'..'d').zip(1..), MapSeparators::new('<', '=', ',', '>'));
+///
+/// assert_eq!(format!("{fmt:?}"), "'<''a''='1',''b''='2',''c''='3'>'");
+/// assert_eq!(format!("{fmt}"), "'<''a''='1',''b''='2',''c''='3'>'");
+/// ```
+pub const fn fmt_separated_debug_map<F, S, I, K, V>(
+    values_fn: F,
+    separators: MapSeparators<S>,
+) -> FmtSeparatedDebugMap<F, S>
+where
+    F: Fn() -> I,
+    I: IntoIterator<Item = (K, V)>,
+    K: Debug,
+    V: Debug,
+    S: Debug,
+{
+    FmtSeparatedDebugMap::new(values_fn, separators)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a list of `(Display, Display)` objects as a map with specified
+/// separators that implement [`Display`].
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::MapSeparators;
+///
+/// let fmt = fmt_tools::fmt_separated_display_map(|| ('a'..'d').zip(1..), MapSeparators::new("", "=", "&", ""));
+///
+/// assert_eq!(format!("{fmt:?}"), "a=1&b=2&c=3");
+/// assert_eq!(format!("{fmt}"), "a=1&b=2&c=3");
+/// ```
+pub const fn fmt_separated_display_map<F, S, I, K, V>(
+    values_fn: F,
+    separators: MapSeparators<S>,
+) -> FmtSeparatedDisplayMap<F, S>
+where
+    F: Fn() -> I,
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+    S: Display,
+{
+    FmtSeparatedDisplayMap::new(values_fn, separators)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FmtSeparatedDebugMap, FmtSeparatedDisplayMap, MapSeparators};
+    use core::fmt::{self, Display, Formatter};
+
+    #[test]
+    fn test_separated_debug_map() {
+        #[derive(Debug)]
+        struct Foo;
+
+        #[derive(Debug)]
+        struct Bar;
+
+        #[derive(Debug)]
+        enum Separator {
+            Opening,
+            KeyValue,
+            Entry,
+            Closing,
+        }
+
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[(Foo, Bar)], "OpeningClosing"),
+            (&[(Foo, Bar)], "OpeningFooKeyValueBarClosing"),
+            (
+                &[(Foo, Bar), (Foo, Bar)],
+                "OpeningFooKeyValueBarEntryFooKeyValueBarClosing",
+            ),
+        ];
+
+        for (values, expected) in test_cases {
+            let fmt = super::fmt_separated_debug_map(
+                || values.iter().map(|(key, value)| (key, value)),
+                MapSeparators::new(
+                    Separator::Opening,
+                    Separator::KeyValue,
+                    Separator::Entry,
+                    Separator::Closing,
+                ),
+            );
+
+            let unsized_fmt: &FmtSeparatedDebugMap<dyn Fn() -> _, Separator> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_separated_display_map() {
+        struct Foo;
+
+        impl Display for Foo {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("foo")
+            }
+        }
+
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[(Foo, u32)], ["", "{}", "\n"]),
+            (&[(Foo, 1)], ["foo=1", "{foo: 1}", "foo: 1\n"]),
+            (
+                &[(Foo, 1), (Foo, 2)],
+                ["foo=1&foo=2", "{foo: 1, foo: 2}", "foo: 1\nfoo: 2\n"],
+            ),
+        ];
+
+        let separators = [
+            MapSeparators::new("", "=", "&", ""),
+            MapSeparators::new("{", ": ", ", ", "}"),
+            MapSeparators::new("", ": ", "\n", "\n"),
+        ];
+
+        for (values, expected_values) in test_cases {
+            for (separators, expected) in separators.into_iter().zip(expected_values) {
+                let fmt =
+                    super::fmt_separated_display_map(|| values.iter().map(|(key, value)| (key, value)), separators);
+
+                let unsized_fmt: &FmtSeparatedDisplayMap<dyn Fn() -> _, &str> = &fmt;
+
+                assert_eq!(std::format!("{fmt:?}"), expected);
+                assert_eq!(std::format!("{fmt}"), expected);
+                assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+                assert_eq!(std::format!("{unsized_fmt}"), expected);
+            }
+        }
+    }
+}
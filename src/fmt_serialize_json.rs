@@ -0,0 +1,965 @@
+use crate::fmt_json::JsonVisitor;
+use crate::fmt_visitor::FmtVisitor;
+use crate::node::Scalar;
+use crate::utilities;
+use core::cell::Cell;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+use serde::ser::{
+    Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ErrorKind {
+    Write,
+    UnsupportedKey,
+    Custom,
+}
+
+/// The error returned by [`FmtSerializeJson::try_write`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SerializeJsonError {
+    kind: ErrorKind,
+}
+
+impl SerializeJsonError {
+    const fn new(kind: ErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+impl Display for SerializeJsonError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self.kind {
+            ErrorKind::Write => "failed to write value",
+            ErrorKind::UnsupportedKey => "unsupported map key type",
+            ErrorKind::Custom => "failed to serialize value",
+        })
+    }
+}
+
+impl serde::ser::StdError for SerializeJsonError {}
+
+impl serde::ser::Error for SerializeJsonError {
+    fn custom<T>(_msg: T) -> Self
+    where
+        T: Display,
+    {
+        Self::new(ErrorKind::Custom)
+    }
+}
+
+impl From<fmt::Error> for SerializeJsonError {
+    fn from(_: fmt::Error) -> Self {
+        Self::new(ErrorKind::Write)
+    }
+}
+
+/// Writes a serializable map key as a string. Strings, characters, booleans, integers and unit variants are supported.
+struct KeySerializer<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+}
+
+macro_rules! serialize_key_with_display {
+    ($($method:ident: $type:ty,)*) => {
+        $(
+            fn $method(self, v: $type) -> Result<(), SerializeJsonError> {
+                self.f.write_fmt(format_args!("{v}")).map_err(SerializeJsonError::from)
+            }
+        )*
+    };
+}
+
+macro_rules! serialize_key_unsupported {
+    ($($method:ident($($arg:ident: $type:ty),*) -> $ok:ty,)*) => {
+        $(
+            fn $method(self, $(_: $type),*) -> Result<$ok, SerializeJsonError> {
+                Err(SerializeJsonError::new(ErrorKind::UnsupportedKey))
+            }
+        )*
+    };
+}
+
+impl Serializer for KeySerializer<'_, '_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+    type SerializeSeq = Impossible<(), SerializeJsonError>;
+    type SerializeTuple = Impossible<(), SerializeJsonError>;
+    type SerializeTupleStruct = Impossible<(), SerializeJsonError>;
+    type SerializeTupleVariant = Impossible<(), SerializeJsonError>;
+    type SerializeMap = Impossible<(), SerializeJsonError>;
+    type SerializeStruct = Impossible<(), SerializeJsonError>;
+    type SerializeStructVariant = Impossible<(), SerializeJsonError>;
+
+    serialize_key_with_display! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_char: char,
+        serialize_str: &str,
+    }
+
+    serialize_key_unsupported! {
+        serialize_f32(v: f32) -> (),
+        serialize_f64(v: f64) -> (),
+        serialize_bytes(v: &[u8]) -> (),
+        serialize_none() -> (),
+        serialize_unit() -> (),
+        serialize_unit_struct(name: &'static str) -> (),
+        serialize_seq(len: Option<usize>) -> Self::SerializeSeq,
+        serialize_tuple(len: usize) -> Self::SerializeTuple,
+        serialize_tuple_struct(name: &'static str, len: usize) -> Self::SerializeTupleStruct,
+        serialize_tuple_variant(
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize
+        ) -> Self::SerializeTupleVariant,
+        serialize_map(len: Option<usize>) -> Self::SerializeMap,
+        serialize_struct(name: &'static str, len: usize) -> Self::SerializeStruct,
+        serialize_struct_variant(
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize
+        ) -> Self::SerializeStructVariant,
+    }
+
+    fn serialize_some<T>(self, _: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(SerializeJsonError::new(ErrorKind::UnsupportedKey))
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<(), SerializeJsonError> {
+        self.f.write_str(variant).map_err(SerializeJsonError::from)
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(SerializeJsonError::new(ErrorKind::UnsupportedKey))
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Display + ?Sized,
+    {
+        self.f
+            .write_fmt(format_args!("{value}"))
+            .map_err(SerializeJsonError::from)
+    }
+}
+
+/// Adapts a serializable value to [`Display`] with `serialize_fn`, recording the serialization error so that it is not
+/// lost when converted to [`fmt::Error`].
+struct Capture<'a, T, S>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    serialize_fn: S,
+    error: Cell<Option<SerializeJsonError>>,
+}
+
+impl<'a, T, S> Capture<'a, T, S>
+where
+    T: ?Sized,
+{
+    const fn new(value: &'a T, serialize_fn: S) -> Self {
+        Self {
+            value,
+            serialize_fn,
+            error: Cell::new(None),
+        }
+    }
+
+    /// Returns the recorded error, or a write error if there is none, which means that the writer failed.
+    fn error(&self) -> SerializeJsonError {
+        self.error.get().unwrap_or(SerializeJsonError::new(ErrorKind::Write))
+    }
+}
+
+impl<T, S> Display for Capture<'_, T, S>
+where
+    T: ?Sized,
+    S: Fn(&T, &mut Formatter) -> Result<(), SerializeJsonError>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (self.serialize_fn)(self.value, f).map_err(|error| {
+            self.error.set(Some(error));
+
+            fmt::Error
+        })
+    }
+}
+
+fn serialize_key<T>(key: &T, f: &mut Formatter) -> Result<(), SerializeJsonError>
+where
+    T: Serialize + ?Sized,
+{
+    key.serialize(KeySerializer { f })
+}
+
+fn serialize_json<T>(value: &T, f: &mut Formatter) -> Result<(), SerializeJsonError>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(VisitorSerializer {
+        visitor: &mut JsonVisitor::new(f),
+    })
+}
+
+/// Describes a serializable value to a [`FmtVisitor`]. Enum variants with data are described as single entry maps
+/// from the variant names to the data, like `serde_json` does.
+struct VisitorSerializer<'a> {
+    visitor: &'a mut dyn FmtVisitor,
+}
+
+impl<'a> VisitorSerializer<'a> {
+    fn scalar(self, scalar: Scalar) -> Result<(), SerializeJsonError> {
+        self.visitor.scalar(scalar).map_err(SerializeJsonError::from)
+    }
+
+    fn begin_variant(&mut self, variant: &'static str) -> Result<(), SerializeJsonError> {
+        self.visitor.begin_map()?;
+        self.visitor.key(&variant)?;
+        self.visitor.value()?;
+
+        Ok(())
+    }
+
+    fn begin_list(self, is_variant: bool) -> Result<Compound<'a>, SerializeJsonError> {
+        self.visitor.begin_list()?;
+
+        Ok(Compound {
+            visitor: self.visitor,
+            is_map: false,
+            is_variant,
+        })
+    }
+
+    fn begin_map(self, is_variant: bool) -> Result<Compound<'a>, SerializeJsonError> {
+        self.visitor.begin_map()?;
+
+        Ok(Compound {
+            visitor: self.visitor,
+            is_map: true,
+            is_variant,
+        })
+    }
+}
+
+impl<'a> Serializer for VisitorSerializer<'a> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Integer(&v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Float(utilities::f32_to_shortest_f64(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Float(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::String(&v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::String(&v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), SerializeJsonError> {
+        let mut seq = self.begin_list(false)?;
+
+        v.iter().try_for_each(|byte| seq.item(byte))?;
+
+        seq.end()
+    }
+
+    fn serialize_none(self) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Null)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::Null)
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<(), SerializeJsonError> {
+        self.scalar(Scalar::String(&variant))
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        mut self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.begin_variant(variant)?;
+
+        value.serialize(VisitorSerializer {
+            visitor: &mut *self.visitor,
+        })?;
+
+        self.visitor.end_map().map_err(SerializeJsonError::from)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Compound<'a>, SerializeJsonError> {
+        self.begin_list(false)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Compound<'a>, SerializeJsonError> {
+        self.begin_list(false)
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, SerializeJsonError> {
+        self.begin_list(false)
+    }
+
+    fn serialize_tuple_variant(
+        mut self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, SerializeJsonError> {
+        self.begin_variant(variant)?;
+        self.begin_list(true)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Compound<'a>, SerializeJsonError> {
+        self.begin_map(false)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, SerializeJsonError> {
+        self.begin_map(false)
+    }
+
+    fn serialize_struct_variant(
+        mut self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, SerializeJsonError> {
+        self.begin_variant(variant)?;
+        self.begin_map(true)
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Display + ?Sized,
+    {
+        self.scalar(Scalar::String(&format_args!("{value}")))
+    }
+}
+
+/// Serializes the items or entries of a list or map, as recorded by `is_map`. `is_variant` records whether the
+/// collection is wrapped in a map that holds the enum variant name, which has to be ended too.
+struct Compound<'a> {
+    visitor: &'a mut dyn FmtVisitor,
+    is_map: bool,
+    is_variant: bool,
+}
+
+impl Compound<'_> {
+    fn item<T>(&mut self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.visitor.entry()?;
+
+        value.serialize(VisitorSerializer {
+            visitor: &mut *self.visitor,
+        })
+    }
+
+    fn field<T>(&mut self, key: &'static str, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.visitor.key(&key)?;
+        self.visitor.value()?;
+
+        value.serialize(VisitorSerializer {
+            visitor: &mut *self.visitor,
+        })
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        if self.is_map {
+            self.visitor.end_map()?;
+        } else {
+            self.visitor.end_list()?;
+        }
+
+        if self.is_variant {
+            self.visitor.end_map()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        self.end()
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        self.end()
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        self.end()
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        self.end()
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = Capture::new(key, serialize_key);
+
+        self.visitor.key(&key).map_err(|fmt::Error| key.error())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.visitor.value()?;
+
+        value.serialize(VisitorSerializer {
+            visitor: &mut *self.visitor,
+        })
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        self.end()
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        self.end()
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeJsonError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), SerializeJsonError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), SerializeJsonError> {
+        self.end()
+    }
+}
+
+/// [`Debug`] or [`Display`] a [`Serialize`] value as JSON.
+///
+/// If the alternate flag is specified in the format string, the JSON is pretty-printed with two spaces of indentation.
+pub struct FmtSerializeJson<T>
+where
+    T: ?Sized,
+{
+    value: T,
+}
+
+impl<T> FmtSerializeJson<T> {
+    const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> FmtSerializeJson<T>
+where
+    T: Serialize + ?Sized,
+{
+    /// Writes the value as JSON to `writer`, pretty-printed with two spaces of indentation if `pretty` is `true`. Unlike
+    /// formatting with [`Debug`] or [`Display`], the reason of a failure is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a map key is not supported, if a [`Serialize`] implementation fails, or if writing fails. The
+    /// nesting depth of the value is not limited.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut buffer = String::new();
+    ///
+    /// fmt_tools::fmt_serialize_json([1, 2]).try_write(&mut buffer, false).unwrap();
+    ///
+    /// assert_eq!(buffer, "[1,2]");
+    ///
+    /// let unsupported = BTreeMap::from([((1, 2), 3)]);
+    /// let error = fmt_tools::fmt_serialize_json(&unsupported).try_write(&mut buffer, false).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "unsupported map key type");
+    /// ```
+    pub fn try_write<W>(&self, writer: &mut W, pretty: bool) -> Result<(), SerializeJsonError>
+    where
+        W: Write + ?Sized,
+    {
+        let value = Capture::new(&self.value, serialize_json);
+
+        if pretty {
+            writer.write_fmt(format_args!("{value:#}"))
+        } else {
+            writer.write_fmt(format_args!("{value}"))
+        }
+        .map_err(|fmt::Error| value.error())
+    }
+}
+
+impl<T> Debug for FmtSerializeJson<T>
+where
+    T: Serialize + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        serialize_json(&self.value, f).map_err(|_| fmt::Error)
+    }
+}
+
+impl<T> Display for FmtSerializeJson<T>
+where
+    T: Serialize + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Serialize`] value as JSON, without allocation. Map keys must
+/// be strings, characters, booleans, integers or unit variants, and non-finite floating point values are written as
+/// `null`.
+///
+/// If the value can not be serialized, formatting fails with [`fmt::Error`], which makes `format!` and `to_string`
+/// panic. Use [`FmtSerializeJson::try_write`] to handle the error instead.
+///
+/// If the alternate flag is specified in the format string, the JSON is pretty-printed with two spaces of indentation.
+///
+/// Example:
+///
+/// ```rust
+/// #[derive(serde::Serialize)]
+/// struct Server {
+///     name: &'static str,
+///     ports: [u16; 2],
+///     tls: Option<bool>,
+/// }
+///
+/// let server = Server {
+///     name: "app",
+///     ports: [80, 443],
+///     tls: None,
+/// };
+///
+/// let fmt = fmt_tools::fmt_serialize_json(&server);
+///
+/// assert_eq!(format!("{fmt:?}"), r#"{"name":"app","ports":[80,443],"tls":null}"#);
+/// assert_eq!(format!("{fmt}"), r#"{"name":"app","ports":[80,443],"tls":null}"#);
+///
+/// assert_eq!(
+///     format!("{fmt:#}"),
+///     r#"{
+///   "name": "app",
+///   "ports": [
+///     80,
+///     443
+///   ],
+///   "tls": null
+/// }"#,
+/// );
+/// ```
+pub const fn fmt_serialize_json<T>(value: T) -> FmtSerializeJson<T>
+where
+    T: Serialize,
+{
+    FmtSerializeJson::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorKind, FmtSerializeJson, SerializeJsonError};
+    use core::fmt::{self, Write};
+    use serde::ser::{Serialize, SerializeMap, Serializer};
+    use std::string::String;
+
+    #[derive(serde::Serialize)]
+    enum Shape {
+        Empty,
+        Circle(f32),
+        Point(i32, i32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(serde::Serialize)]
+    struct Unit;
+
+    #[derive(serde::Serialize)]
+    struct Meters(u8);
+
+    struct Pairs<'a, K>(&'a [(K, u32)]);
+
+    impl<K> Serialize for Pairs<'_, K>
+    where
+        K: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+            self.0
+                .iter()
+                .try_for_each(|(key, value)| map.serialize_entry(key, value))?;
+
+            map.end()
+        }
+    }
+
+    fn check<T>(value: &T, expected: &str)
+    where
+        T: Serialize,
+    {
+        let fmt = super::fmt_serialize_json(value);
+
+        assert_eq!(std::format!("{fmt:?}"), expected);
+        assert_eq!(std::format!("{fmt}"), expected);
+        assert_eq!(serde_json::to_string(value).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_fmt_serialize_json() {
+        check(&(), "null");
+        check(&Unit, "null");
+        check(&Some(true), "true");
+        check(&Meters(3), "3");
+        check(&-7_i64, "-7");
+        check(&u128::MAX, "340282366920938463463374607431768211455");
+        check(&2.5_f64, "2.5");
+        check(&'\n', r#""\n""#);
+        check(&"a\"\\\u{1f}é", r#""a\"\\\u001fé""#);
+        check(&[0_u8; 0], "[]");
+        check(&(1, "a", [false]), r#"[1,"a",[false]]"#);
+        check(&Shape::Empty, r#""Empty""#);
+        check(&Shape::Circle(1.5), r#"{"Circle":1.5}"#);
+        check(&Shape::Point(1, -2), r#"{"Point":[1,-2]}"#);
+        check(
+            &Shape::Rect { width: 3, height: 4 },
+            r#"{"Rect":{"width":3,"height":4}}"#,
+        );
+        check(&Pairs::<u8>(&[]), "{}");
+        check(&Pairs(&[(1, 2), (3, 4)]), r#"{"1":2,"3":4}"#);
+        check(&Pairs(&[('a', 1), ('"', 2)]), r#"{"a":1,"\"":2}"#);
+
+        let unsized_fmt: &FmtSerializeJson<[u32]> = &super::fmt_serialize_json([1, 2]);
+
+        assert_eq!(std::format!("{unsized_fmt}"), "[1,2]");
+    }
+
+    #[test]
+    fn test_fmt_serialize_json_alternate() {
+        let value = (Shape::Rect { width: 3, height: 4 }, [0_u8; 0], Pairs::<u8>(&[]));
+
+        assert_eq!(
+            std::format!("{:#}", super::fmt_serialize_json(&value)),
+            "[\n  {\n    \"Rect\": {\n      \"width\": 3,\n      \"height\": 4\n    }\n  },\n  [],\n  {}\n]"
+        );
+    }
+
+    #[test]
+    fn test_fmt_serialize_json_f32() {
+        check(&0.1_f32, "0.1");
+        check(&1e-7_f32, "1e-7");
+        check(&16_777_216_f32, "16777216.0");
+        check(&f32::MIN_POSITIVE, "1.1754944e-38");
+        check(&-0.3_f32, "-0.3");
+        check(&f32::NAN, "null");
+
+        // `serde_json` writes `3.4028235e+38`, which is equivalent.
+        assert_eq!(std::format!("{}", super::fmt_serialize_json(f32::MAX)), "3.4028235e38");
+    }
+
+    #[test]
+    fn test_fmt_serialize_json_try_write() {
+        let mut buffer = String::new();
+
+        super::fmt_serialize_json((1, [2]))
+            .try_write(&mut buffer, false)
+            .unwrap();
+        buffer.push('|');
+        super::fmt_serialize_json([1]).try_write(&mut buffer, true).unwrap();
+
+        assert_eq!(buffer, "[1,[2]]|[\n  1\n]");
+    }
+
+    #[test]
+    fn test_fmt_serialize_json_deep_nesting() {
+        let value = (0..200).fold(serde_json::Value::Null, |value, _| {
+            serde_json::Value::Array(std::vec![value])
+        });
+        let mut buffer = String::new();
+
+        super::fmt_serialize_json(&value).try_write(&mut buffer, false).unwrap();
+
+        assert_eq!(buffer, serde_json::to_string(&value).unwrap());
+    }
+
+    #[test]
+    fn test_fmt_serialize_json_errors() {
+        type WriteFn = dyn Fn(&mut dyn Write) -> Result<(), SerializeJsonError>;
+
+        #[derive(serde::Serialize)]
+        enum Key {
+            Newtype(u8),
+        }
+
+        struct Failing;
+
+        impl Serialize for Failing {
+            fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                Err(serde::ser::Error::custom("failing"))
+            }
+        }
+
+        struct LimitedWriter(usize);
+
+        impl Write for LimitedWriter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 = self.0.checked_sub(s.len()).ok_or(fmt::Error)?;
+
+                Ok(())
+            }
+        }
+
+        let unsupported_key = SerializeJsonError::new(ErrorKind::UnsupportedKey);
+        let custom = SerializeJsonError::new(ErrorKind::Custom);
+
+        let test_cases: [(&WriteFn, _); 7] = [
+            (
+                &|w| super::fmt_serialize_json(Pairs(&[((), 1)])).try_write(w, false),
+                unsupported_key,
+            ),
+            (
+                &|w| super::fmt_serialize_json(Pairs(&[([1], 1)])).try_write(w, false),
+                unsupported_key,
+            ),
+            (
+                &|w| super::fmt_serialize_json(Pairs(&[(Some(1), 1)])).try_write(w, false),
+                unsupported_key,
+            ),
+            (
+                &|w| super::fmt_serialize_json(Pairs(&[((1, 2), 1)])).try_write(w, false),
+                unsupported_key,
+            ),
+            (
+                &|w| super::fmt_serialize_json(Pairs(&[(Key::Newtype(1), 1)])).try_write(w, false),
+                unsupported_key,
+            ),
+            (
+                &|w| super::fmt_serialize_json(Pairs(&[(Failing, 1)])).try_write(w, false),
+                custom,
+            ),
+            (&|w| super::fmt_serialize_json([Failing]).try_write(w, true), custom),
+        ];
+
+        for (write_fn, expected) in test_cases {
+            assert_eq!(write_fn(&mut String::new()), Err(expected));
+        }
+
+        assert_eq!(
+            super::fmt_serialize_json([1, 2, 3]).try_write(&mut LimitedWriter(4), false),
+            Err(SerializeJsonError::new(ErrorKind::Write))
+        );
+        assert_eq!(
+            std::format!("{unsupported_key} / {custom}"),
+            "unsupported map key type / failed to serialize value"
+        );
+
+        let mut buffer = String::new();
+
+        assert!(buffer
+            .write_fmt(format_args!("{}", super::fmt_serialize_json(Pairs(&[((), 1)]))))
+            .is_err());
+
+        assert!(buffer
+            .write_fmt(format_args!("{}", super::fmt_serialize_json([Failing])))
+            .is_err());
+    }
+}
@@ -1,6 +1,10 @@
-use crate::fmt_display;
+use crate::node::{Node, NodeKind};
+use crate::{fmt_display, fmt_fn};
 use core::fmt::{self, Debug, Display, Formatter};
 
+#[cfg(feature = "ufmt")]
+use crate::fmt_ufmt_fn::UDisplayAsDebug;
+
 /// [`Debug`] or [`Display`] a list of [`Debug`] objects as a set.
 pub struct FmtDebugSet<F>
 where
@@ -39,6 +43,117 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<F, I> defmt::Format for FmtDebugSet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: defmt::Format,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{{");
+
+        crate::fmt_separated_list::defmt_separated(
+            (self.values_fn)(),
+            fmt,
+            |item, fmt| defmt::write!(fmt, "{}", item),
+            |fmt| defmt::write!(fmt, ", "),
+        );
+
+        defmt::write!(fmt, "}}");
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I> ufmt::uDebug for FmtDebugSet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDebug,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.debug_set()?.entries((self.values_fn)())?.finish()
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I> ufmt::uDisplay for FmtDebugSet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDebug,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDebug::fmt(self, f)
+    }
+}
+
+impl<F, I> Node for FmtDebugSet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: Node,
+{
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::List
+    }
+
+    fn for_each_item(&self, item_fn: &mut dyn FnMut(&dyn Node) -> fmt::Result) -> fmt::Result {
+        (self.values_fn)().into_iter().try_for_each(|item| item_fn(&item))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F, I> serde::Serialize for FmtDebugSet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq((self.values_fn)())
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<F, I> valuable::Valuable for FmtDebugSet<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    I::Item: valuable::Valuable,
+{
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Listable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        (self.values_fn)()
+            .into_iter()
+            .for_each(|item| visit.visit_value(item.as_value()));
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<F, I> valuable::Listable for FmtDebugSet<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    I::Item: valuable::Valuable,
+{
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.values_fn)().into_iter().size_hint()
+    }
+}
+
 /// [`Debug`] or [`Display`] a list of [`Display`] objects as a set.
 pub struct FmtDisplaySet<F>
 where
@@ -77,6 +192,118 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<F, I> defmt::Format for FmtDisplaySet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: Display,
+{
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{{");
+
+        crate::fmt_separated_list::defmt_separated(
+            (self.values_fn)(),
+            fmt,
+            |item, fmt| defmt::write!(fmt, "{}", defmt::Display2Format(item)),
+            |fmt| defmt::write!(fmt, ", "),
+        );
+
+        defmt::write!(fmt, "}}");
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I> ufmt::uDebug for FmtDisplaySet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDisplay,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let entries = (self.values_fn)().into_iter().map(UDisplayAsDebug);
+
+        f.debug_set()?.entries(entries)?.finish()
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<F, I> ufmt::uDisplay for FmtDisplaySet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: ufmt::uDisplay,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDebug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F, I> serde::Serialize for FmtDisplaySet<F>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    I::Item: Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq((self.values_fn)().into_iter().map(fmt_display))
+    }
+}
+
+/// [`Debug`] or [`Display`] a list of objects as a set, formatting each object with a function object.
+pub struct FmtDebugSetWith<F, W>
+where
+    F: ?Sized,
+{
+    value_writer: W,
+    values_fn: F,
+}
+
+impl<F, W> FmtDebugSetWith<F, W> {
+    const fn new(values_fn: F, value_writer: W) -> Self {
+        Self {
+            value_writer,
+            values_fn,
+        }
+    }
+}
+
+impl<F, W, I> Debug for FmtDebugSetWith<F, W>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    W: Fn(&I::Item, &mut Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let entries = (self.values_fn)()
+            .into_iter()
+            .map(|value| fmt_fn(move |f| (self.value_writer)(&value, f)));
+
+        f.debug_set().entries(entries).finish()
+    }
+}
+
+impl<F, W, I> Display for FmtDebugSetWith<F, W>
+where
+    F: Fn() -> I + ?Sized,
+    I: IntoIterator,
+    W: Fn(&I::Item, &mut Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Creates an object that [`Debug`] or [`Display`] a list of [`Debug`] objects as a set.
 ///
 /// Example:
@@ -115,10 +342,30 @@ where
     FmtDisplaySet::new(values_fn)
 }
 
+/// Creates an object that [`Debug`] or [`Display`] a list of objects as a set, formatting each object with the
+/// provided function object.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_debug_set_with(|| 10..13, |value, f| write!(f, "{value:#x}"));
+///
+/// assert_eq!(format!("{fmt:?}"), "{0xa, 0xb, 0xc}");
+/// assert_eq!(format!("{fmt}"), "{0xa, 0xb, 0xc}");
+/// ```
+pub const fn fmt_debug_set_with<F, W, I>(values_fn: F, value_writer: W) -> FmtDebugSetWith<F, W>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    W: Fn(&I::Item, &mut Formatter) -> fmt::Result,
+{
+    FmtDebugSetWith::new(values_fn, value_writer)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{FmtDebugSet, FmtDisplaySet};
-    use core::fmt::{self, Display, Formatter};
+    use super::{FmtDebugSet, FmtDebugSetWith, FmtDisplaySet};
+    use core::fmt::{self, Display, Formatter, Write};
 
     #[test]
     fn test_debug_fmt_set() {
@@ -172,4 +419,113 @@ mod tests {
             assert_eq!(std::format!("{unsized_fmt}"), expected);
         }
     }
+
+    #[test]
+    fn test_debug_fmt_set_with() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[u32], "{}"),
+            (&[1], "{<1>}"),
+            (&[1, 2], "{<1>, <2>}"),
+            (&[1, 2, 3], "{<1>, <2>, <3>}"),
+        ];
+
+        for (values, expected) in test_cases {
+            let fmt = super::fmt_debug_set_with(|| values, |value, f| f.write_fmt(format_args!("<{value}>")));
+            let unsized_fmt: &FmtDebugSetWith<dyn Fn() -> &'static [u32], _> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_debug_fmt_set_with_alternate() {
+        let fmt = super::fmt_debug_set_with(
+            || [1, 2],
+            |value, f| {
+                f.write_char(if f.alternate() { 'A' } else { 'B' })
+                    .and_then(|()| value.fmt(f))
+            },
+        );
+
+        assert_eq!(std::format!("{fmt:#?}"), "{\n    A1,\n    A2,\n}");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_fmt_set() {
+        let debug_set = super::fmt_debug_set(|| [Some(1), None]);
+        let display_set = super::fmt_display_set(|| [1.5, 2.0]);
+
+        assert_eq!(serde_json::to_string(&debug_set).unwrap(), "[1,null]");
+        assert_eq!(serde_json::to_string(&display_set).unwrap(), r#"["1.5","2"]"#);
+    }
+
+    #[cfg(feature = "valuable")]
+    #[test]
+    fn test_valuable_fmt_debug_set() {
+        use valuable::{Listable, Valuable, Value};
+
+        let set = super::fmt_debug_set(|| [1, 2, 3]);
+
+        assert!(matches!(set.as_value(), Value::Listable(_)));
+        assert_eq!(set.size_hint(), (3, Some(3)));
+        assert_eq!(std::format!("{:?}", set.as_value()), "[1, 2, 3]");
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_ufmt_fmt_set() {
+        let debug_set = super::fmt_debug_set(|| [Some(1), None]);
+        let display_set = super::fmt_display_set(|| ["a", "b"]);
+        let mut buffer = std::string::String::new();
+
+        ufmt::uwrite!(buffer, "{:?} {} {}", debug_set, debug_set, display_set).unwrap();
+
+        assert_eq!(buffer, "{Some(1), None} {Some(1), None} {a, b}");
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_fmt_set() {
+        fn assert_format<T>(_: &T)
+        where
+            T: defmt::Format + ?Sized,
+        {
+        }
+
+        let debug_set = super::fmt_debug_set(|| [1, 2]);
+        let display_set = super::fmt_display_set(|| ["a", "b"]);
+        let unsized_debug_set: &FmtDebugSet<dyn Fn() -> [i32; 2]> = &debug_set;
+        let unsized_display_set: &FmtDisplaySet<dyn Fn() -> [&'static str; 2]> = &display_set;
+
+        assert_format(&debug_set);
+        assert_format(&display_set);
+        assert_format(unsized_debug_set);
+        assert_format(unsized_display_set);
+
+        let (index, bytes) = crate::utilities::defmt_encode(&debug_set);
+        let istr = |offset: u16| (index + offset).to_le_bytes();
+
+        assert_eq!(
+            bytes,
+            [
+                &istr(0)[..], // Format sequence.
+                &istr(1),     // "{{"
+                &istr(2),     // "{}"
+                &istr(3),     // "{=i32}"
+                &1_i32.to_le_bytes(),
+                &istr(4), // ", "
+                &istr(5), // "{}"
+                &istr(6), // "{=i32}"
+                &2_i32.to_le_bytes(),
+                &istr(7), // "}}"
+                &[0, 0],  // End of the format sequence.
+            ]
+            .concat()
+        );
+    }
 }
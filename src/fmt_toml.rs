@@ -0,0 +1,331 @@
+use crate::node::{Node, NodeKind, Scalar};
+use crate::utilities;
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+fn write_string(args: Arguments, f: &mut Formatter) -> fmt::Result {
+    f.write_char('"')?;
+
+    utilities::try_for_each_char(args, |c| match c {
+        '"' => f.write_str("\\\""),
+        '\\' => f.write_str("\\\\"),
+        '\u{8}' => f.write_str("\\b"),
+        '\t' => f.write_str("\\t"),
+        '\n' => f.write_str("\\n"),
+        '\u{c}' => f.write_str("\\f"),
+        '\r' => f.write_str("\\r"),
+        c if c.is_control() => f.write_fmt(format_args!("\\u{:04X}", u32::from(c))),
+        c => f.write_char(c),
+    })?;
+
+    f.write_char('"')
+}
+
+fn write_key(args: Arguments, f: &mut Formatter) -> fmt::Result {
+    let mut is_empty = true;
+    let mut is_bare = true;
+
+    utilities::try_for_each_char(args, |c| {
+        is_empty = false;
+        is_bare &= c.is_ascii_alphanumeric() || matches!(c, '-' | '_');
+
+        Ok(())
+    })?;
+
+    if is_bare && !is_empty {
+        f.write_fmt(args)
+    } else {
+        write_string(args, f)
+    }
+}
+
+/// Writes a node as an inline value. Null values are not supported by TOML, so null items and entries of collections
+/// are skipped, and a null node itself writes nothing.
+fn write_inline(node: &dyn Node, f: &mut Formatter) -> fmt::Result {
+    match node.kind() {
+        NodeKind::Scalar(Scalar::Null) => Ok(()),
+        NodeKind::Scalar(Scalar::Bool(value)) => f.write_str(if value { "true" } else { "false" }),
+        NodeKind::Scalar(Scalar::Integer(value)) => f.write_fmt(format_args!("{value}")),
+        NodeKind::Scalar(Scalar::Float(value)) => {
+            if value.is_nan() {
+                f.write_str("nan")
+            } else if value.is_infinite() {
+                f.write_str(if value.is_sign_negative() { "-inf" } else { "inf" })
+            } else {
+                f.write_fmt(format_args!("{value:?}"))
+            }
+        }
+        NodeKind::Scalar(Scalar::String(value)) => write_string(format_args!("{value}"), f),
+        NodeKind::List => {
+            let mut first = true;
+
+            f.write_char('[')?;
+
+            node.for_each_item(&mut |item| {
+                if matches!(item.kind(), NodeKind::Scalar(Scalar::Null)) {
+                    return Ok(());
+                }
+
+                if first {
+                    first = false;
+                } else {
+                    f.write_str(", ")?;
+                }
+
+                write_inline(item, f)
+            })?;
+
+            f.write_char(']')
+        }
+        NodeKind::Map => {
+            let mut first = true;
+
+            f.write_char('{')?;
+
+            node.for_each_entry(&mut |key, value| {
+                if matches!(value.kind(), NodeKind::Scalar(Scalar::Null)) {
+                    return Ok(());
+                }
+
+                f.write_str(if first { " " } else { ", " })?;
+                first = false;
+
+                write_key(format_args!("{key}"), f)?;
+                f.write_str(" = ")?;
+
+                write_inline(value, f)
+            })?;
+
+            f.write_str(if first { "}" } else { " }" })
+        }
+    }
+}
+
+/// A linked list of keys that leads to a table, used for writing table headers without allocation.
+struct TablePath<'a> {
+    parent: Option<&'a Self>,
+    key: &'a dyn Display,
+}
+
+impl Display for TablePath<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(parent) = self.parent {
+            parent.fmt(f)?;
+            f.write_char('.')?;
+        }
+
+        write_key(format_args!("{}", self.key), f)
+    }
+}
+
+/// Writes a `key = value` line. `has_output` records whether anything has been written, so that lines can be separated
+/// properly.
+fn write_key_value(key: &dyn Display, value: &dyn Node, f: &mut Formatter, has_output: &mut bool) -> fmt::Result {
+    if *has_output {
+        f.write_char('\n')?;
+    }
+
+    *has_output = true;
+
+    write_key(format_args!("{key}"), f)?;
+    f.write_str(" = ")?;
+
+    write_inline(value, f)
+}
+
+/// Writes key/value pairs of a table, followed by its sub-tables. `has_output` records whether anything has been
+/// written, so that lines and tables can be separated properly.
+fn write_table(node: &dyn Node, path: Option<&TablePath>, f: &mut Formatter, has_output: &mut bool) -> fmt::Result {
+    node.for_each_entry(&mut |key, value| match value.kind() {
+        NodeKind::Scalar(Scalar::Null) | NodeKind::Map => Ok(()),
+        NodeKind::Scalar(_) | NodeKind::List => write_key_value(key, value, f, has_output),
+    })?;
+
+    node.for_each_entry(&mut |key, value| {
+        if matches!(value.kind(), NodeKind::Map) {
+            let path = TablePath { parent: path, key };
+
+            if *has_output {
+                f.write_str("\n\n")?;
+            }
+
+            *has_output = true;
+
+            f.write_fmt(format_args!("[{path}]"))?;
+
+            write_table(value, Some(&path), f, has_output)
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// [`Debug`] or [`Display`] a [`Node`] as a TOML document.
+pub struct FmtToml<T>
+where
+    T: ?Sized,
+{
+    value: T,
+}
+
+impl<T> FmtToml<T> {
+    const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> Debug for FmtToml<T>
+where
+    T: Node + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.value.kind() {
+            NodeKind::Scalar(Scalar::Null) => Ok(()),
+            NodeKind::Scalar(_) | NodeKind::List => write_key_value(&"value", &&self.value, f, &mut false),
+            NodeKind::Map => write_table(&&self.value, None, f, &mut false),
+        }
+    }
+}
+
+impl<T> Display for FmtToml<T>
+where
+    T: Node + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a map [`Node`] as a TOML document. Nested maps are written as
+/// tables, other collections are written as inline arrays and inline tables, and null values are skipped.
+///
+/// TOML documents are always tables, so a root node that is not a map is written as a single `value = ...` line, and a
+/// null root node is written as an empty document.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::Node;
+///
+/// let database = fmt_tools::fmt_debug_map(|| [("host", "db.local"), ("user", "admin")]);
+///
+/// let fmt = fmt_tools::fmt_toml(fmt_tools::fmt_debug_map(|| {
+///     [
+///         ("name", &"app" as &dyn Node),
+///         ("ports", &[80, 443]),
+///         ("database", &database),
+///     ]
+/// }));
+///
+/// let expected = "\
+/// name = \"app\"
+/// ports = [80, 443]
+///
+/// [database]
+/// host = \"db.local\"
+/// user = \"admin\"";
+///
+/// assert_eq!(format!("{fmt:?}"), expected);
+/// assert_eq!(format!("{fmt}"), expected);
+/// ```
+pub const fn fmt_toml<T>(value: T) -> FmtToml<T>
+where
+    T: Node,
+{
+    FmtToml::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtToml;
+    use crate::node::Node;
+
+    #[test]
+    fn test_fmt_toml_values() {
+        let empty_map = crate::fmt_debug_map(|| [(1, 2); 0]);
+        let inline_map = crate::fmt_debug_map(|| [("a", 1), ("b c", 2)]);
+        let list_of_maps = [&inline_map];
+
+        let test_cases: [(&dyn Node, &str); 13] = [
+            (&true, "true"),
+            (&-3, "-3"),
+            (&2.5, "2.5"),
+            (&0.1_f32, "0.1"),
+            (&f64::NAN, "nan"),
+            (&f64::NEG_INFINITY, "-inf"),
+            (&'x', "\"x\""),
+            (&"say \"hi\"\\\n\u{1}", r#""say \"hi\"\\\n\u0001""#),
+            (&[0_u32; 0], "[]"),
+            (&[1, 2], "[1, 2]"),
+            (&[[1], [2]], "[[1], [2]]"),
+            (&[&empty_map], "[{}]"),
+            (&list_of_maps, r#"[{ a = 1, "b c" = 2 }]"#),
+        ];
+
+        for (value, expected) in test_cases {
+            let fmt = super::fmt_toml(crate::fmt_debug_map(|| [("key", value)]));
+
+            assert_eq!(std::format!("{fmt}"), std::format!("key = {expected}"));
+        }
+    }
+
+    #[test]
+    fn test_fmt_toml_tables() {
+        let empty_map = crate::fmt_debug_map(|| [(1, 2); 0]);
+        #[allow(trivial_casts)]
+        let inner = crate::fmt_debug_map(|| [("x", &1 as &dyn Node), ("empty", &empty_map)]);
+
+        #[allow(trivial_casts)]
+        let fmt = super::fmt_toml(crate::fmt_debug_map(|| {
+            [("a.b", &inner as &dyn Node), ("skipped", &None::<u32>), ("c", &true)]
+        }));
+
+        let unsized_fmt: &FmtToml<dyn Node> = &fmt;
+
+        let expected = "\
+c = true
+
+[\"a.b\"]
+x = 1
+
+[\"a.b\".empty]";
+
+        assert_eq!(std::format!("{fmt:?}"), expected);
+        assert_eq!(std::format!("{fmt}"), expected);
+        assert_eq!(std::format!("{unsized_fmt}"), expected);
+
+        let only_tables = super::fmt_toml(crate::fmt_debug_map(|| [("t", &empty_map)]));
+
+        assert_eq!(std::format!("{only_tables}"), "[t]");
+    }
+
+    #[test]
+    fn test_fmt_toml_nulls() {
+        #[allow(trivial_casts)]
+        let inline_map = crate::fmt_debug_map(|| [("a", &None::<u32> as &dyn Node), ("b", &1), ("c", &None::<u32>)]);
+        let null_map = crate::fmt_debug_map(|| [("a", None::<u32>)]);
+
+        #[allow(trivial_casts)]
+        let test_cases: [(&dyn Node, &str); 5] = [
+            (&[None, Some(1), None, Some(2)], "key = [1, 2]"),
+            (&[None::<u32>], "key = []"),
+            (&[&inline_map], "key = [{ b = 1 }]"),
+            (&[&null_map], "key = [{}]"),
+            (&[&[None::<u32>] as &dyn Node, &[Some(1)]], "key = [[], [1]]"),
+        ];
+
+        for (value, expected) in test_cases {
+            let fmt = super::fmt_toml(crate::fmt_debug_map(|| [("key", value)]));
+
+            assert_eq!(std::format!("{fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_fmt_toml_non_map_root() {
+        assert_eq!(std::format!("{}", super::fmt_toml([1, 2])), "value = [1, 2]");
+        assert_eq!(std::format!("{:?}", super::fmt_toml("x")), "value = \"x\"");
+        assert_eq!(std::format!("{}", super::fmt_toml(true)), "value = true");
+        assert_eq!(std::format!("{}", super::fmt_toml([None, Some(1)])), "value = [1]");
+        assert_eq!(std::format!("{}", super::fmt_toml(None::<u32>)), "");
+    }
+}
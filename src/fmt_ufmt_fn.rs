@@ -0,0 +1,194 @@
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+/// The error type of the writer passed to the function object of [`FmtUfmtFn`]. It can only be obtained from a failed
+/// write, and is converted back to the error type of the underlying writer.
+#[derive(Debug)]
+pub struct UfmtWriteError(());
+
+/// Adapts a formatter with any writer type to a writer with a fixed error type, so that it can be passed to a function
+/// object as a trait object.
+struct ErasedWriter<'a, 'b, W>
+where
+    W: uWrite + ?Sized,
+{
+    error: Option<W::Error>,
+    f: &'a mut Formatter<'b, W>,
+}
+
+impl<W> ErasedWriter<'_, '_, W>
+where
+    W: uWrite + ?Sized,
+{
+    fn record(&mut self, result: Result<(), W::Error>) -> Result<(), UfmtWriteError> {
+        result.map_err(|error| {
+            self.error = Some(error);
+
+            UfmtWriteError(())
+        })
+    }
+}
+
+impl<W> uWrite for ErasedWriter<'_, '_, W>
+where
+    W: uWrite + ?Sized,
+{
+    type Error = UfmtWriteError;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let result = self.f.write_str(s);
+
+        self.record(result)
+    }
+
+    fn write_char(&mut self, c: char) -> Result<(), Self::Error> {
+        let result = self.f.write_char(c);
+
+        self.record(result)
+    }
+}
+
+/// Implements [`uDebug`] and [`uDisplay`] based on a function object.
+pub struct FmtUfmtFn<F>
+where
+    F: ?Sized,
+{
+    values_fn: F,
+}
+
+impl<F> FmtUfmtFn<F> {
+    const fn new(values_fn: F) -> Self {
+        Self { values_fn }
+    }
+}
+
+impl<F> uDisplay for FmtUfmtFn<F>
+where
+    F: Fn(&mut Formatter<dyn uWrite<Error = UfmtWriteError> + '_>) -> Result<(), UfmtWriteError> + ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let mut writer = ErasedWriter { error: None, f };
+        let result = (self.values_fn)(&mut Formatter::new(&mut writer));
+
+        // A failed write is reported even if the function object ignored it. An error that did not come from the writer
+        // has nothing to be converted to, so it is ignored.
+        match (result, writer.error) {
+            (_, Some(error)) => Err(error),
+            (Ok(()) | Err(UfmtWriteError(())), None) => Ok(()),
+        }
+    }
+}
+
+impl<F> uDebug for FmtUfmtFn<F>
+where
+    F: Fn(&mut Formatter<dyn uWrite<Error = UfmtWriteError> + '_>) -> Result<(), UfmtWriteError> + ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uDisplay::fmt(self, f)
+    }
+}
+
+/// Creates an object that implements [`uDebug`] and [`uDisplay`] based on the provided function object. Since function
+/// objects can not be generic, the writer is passed as a trait object, and the pretty-printing flag is not preserved.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_ufmt_fn(|f| ufmt::uwrite!(f, "{}-{}", 1, 2));
+/// let mut buffer = String::new();
+///
+/// ufmt::uwrite!(buffer, "{:?} {}", fmt, fmt).unwrap();
+///
+/// assert_eq!(buffer, "1-2 1-2");
+/// ```
+pub const fn fmt_ufmt_fn<F>(f: F) -> FmtUfmtFn<F>
+where
+    F: Fn(&mut Formatter<dyn uWrite<Error = UfmtWriteError> + '_>) -> Result<(), UfmtWriteError>,
+{
+    FmtUfmtFn::new(f)
+}
+
+/// Implements [`uDebug`] for a [`uDisplay`] value, used for writing [`uDisplay`] items with the debug helpers of
+/// [`Formatter`].
+pub(crate) struct UDisplayAsDebug<T>(pub(crate) T);
+
+impl<T> uDebug for UDisplayAsDebug<T>
+where
+    T: uDisplay,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FmtUfmtFn, UfmtWriteError};
+    use core::convert::Infallible;
+    use ufmt::{uWrite, Formatter};
+
+    type Writer<'a> = dyn uWrite<Error = UfmtWriteError> + 'a;
+    type WriterFn = dyn Fn(&mut Formatter<Writer>) -> Result<(), UfmtWriteError>;
+
+    struct LimitedWriter {
+        remaining: usize,
+    }
+
+    impl uWrite for LimitedWriter {
+        type Error = usize;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.remaining = self.remaining.checked_sub(s.len()).ok_or(self.remaining)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fmt_ufmt_fn() {
+        let fmt = super::fmt_ufmt_fn(|f| f.write_str("foo"));
+        let unsized_fmt: &FmtUfmtFn<WriterFn> = &fmt;
+        let mut buffer = std::string::String::new();
+
+        ufmt::uwrite!(buffer, "{:?} {} {:#?}", fmt, unsized_fmt, fmt).unwrap();
+
+        assert_eq!(buffer, "foo foo foo");
+    }
+
+    #[test]
+    fn test_fmt_ufmt_fn_errors() {
+        let fmt = super::fmt_ufmt_fn(|f| {
+            f.write_str("foo")?;
+            f.write_str("bar")
+        });
+
+        assert_eq!(ufmt::uwrite!(LimitedWriter { remaining: 4 }, "{}", fmt), Err(4 - 3));
+
+        let swallowed = super::fmt_ufmt_fn(|f| f.write_str("foo").or(Ok(())));
+
+        assert_eq!(ufmt::uwrite!(LimitedWriter { remaining: 2 }, "{}", swallowed), Err(2));
+        assert_eq!(
+            ufmt::uwrite!(LimitedWriter { remaining: 3 }, "{}", swallowed),
+            Ok::<_, usize>(())
+        );
+
+        let ignored = super::fmt_ufmt_fn(|_| Err(UfmtWriteError(())));
+
+        assert_eq!(
+            ufmt::uwrite!(LimitedWriter { remaining: 0 }, "{}", ignored),
+            Ok::<_, usize>(())
+        );
+
+        let mut buffer = std::string::String::new();
+
+        assert_eq!(ufmt::uwrite!(buffer, "{}", ignored), Ok::<_, Infallible>(()));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::node::Scalar;
+use crate::utilities;
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Receives the description of a structured value, usually from [`Node::visit`](`crate::Node::visit`).
+///
+/// A list is described by a [`begin_list`](`Self::begin_list`) call, followed by an [`entry`](`Self::entry`) call and
+/// the description of the item for each item, followed by an [`end_list`](`Self::end_list`) call. A map is described by
+/// a [`begin_map`](`Self::begin_map`) call, followed by a [`key`](`Self::key`) call, a [`value`](`Self::value`) call
+/// and the description of the value for each entry, followed by an [`end_map`](`Self::end_map`) call.
+pub trait FmtVisitor {
+    /// Visits a scalar value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value can not be written.
+    fn scalar(&mut self, scalar: Scalar) -> fmt::Result;
+
+    /// Starts a list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list can not be written.
+    fn begin_list(&mut self) -> fmt::Result;
+
+    /// Starts a list item.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the item can not be written.
+    fn entry(&mut self) -> fmt::Result;
+
+    /// Starts a map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the map can not be written.
+    fn begin_map(&mut self) -> fmt::Result;
+
+    /// Starts a map entry with the specified key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key can not be written.
+    fn key(&mut self, key: &dyn Display) -> fmt::Result;
+
+    /// Starts the value of the current map entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value can not be written.
+    fn value(&mut self) -> fmt::Result;
+
+    /// Ends the current list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list can not be written.
+    fn end_list(&mut self) -> fmt::Result;
+
+    /// Ends the current map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the map can not be written.
+    fn end_map(&mut self) -> fmt::Result;
+}
+
+/// A [`FmtVisitor`] that writes values in the style of derived [`Debug`] implementations. Strings are quoted and map
+/// keys are written with their [`Display`] implementations.
+///
+/// If the alternate flag of the formatter is specified, values are pretty-printed like `{:#?}`.
+pub struct DebugVisitor<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    depth: usize,
+    has_entries: bool,
+}
+
+impl<'a, 'b> DebugVisitor<'a, 'b> {
+    /// Creates a new `DebugVisitor` that writes to `f`.
+    pub fn new(f: &'a mut Formatter<'b>) -> Self {
+        Self {
+            f,
+            depth: 0,
+            has_entries: false,
+        }
+    }
+
+    fn begin(&mut self, opening: char) -> fmt::Result {
+        self.depth += 1;
+        self.has_entries = false;
+
+        self.f.write_char(opening)
+    }
+
+    fn write_separator(&mut self) -> fmt::Result {
+        if self.f.alternate() {
+            self.f.write_str(if self.has_entries { ",\n" } else { "\n" })?;
+
+            (0..self.depth).try_for_each(|_| self.f.write_str("    "))?;
+        } else if self.has_entries {
+            self.f.write_str(", ")?;
+        }
+
+        self.has_entries = true;
+
+        Ok(())
+    }
+
+    fn end(&mut self, closing: char) -> fmt::Result {
+        self.depth = self.depth.saturating_sub(1);
+
+        if self.has_entries && self.f.alternate() {
+            self.f.write_str(",\n")?;
+
+            (0..self.depth).try_for_each(|_| self.f.write_str("    "))?;
+        }
+
+        // The parent collection, if any, has at least one entry, which is the one that just ended.
+        self.has_entries = true;
+
+        self.f.write_char(closing)
+    }
+}
+
+impl FmtVisitor for DebugVisitor<'_, '_> {
+    fn scalar(&mut self, scalar: Scalar) -> fmt::Result {
+        match scalar {
+            Scalar::Null => self.f.write_str("None"),
+            Scalar::Bool(value) => self.f.write_str(if value { "true" } else { "false" }),
+            Scalar::Integer(value) => self.f.write_fmt(format_args!("{value}")),
+            Scalar::Float(value) => self.f.write_fmt(format_args!("{value:?}")),
+            Scalar::String(value) => {
+                self.f.write_char('"')?;
+
+                utilities::try_for_each_char(format_args!("{value}"), |c| {
+                    c.escape_debug().try_for_each(|c| self.f.write_char(c))
+                })?;
+
+                self.f.write_char('"')
+            }
+        }
+    }
+
+    fn begin_list(&mut self) -> fmt::Result {
+        self.begin('[')
+    }
+
+    fn entry(&mut self) -> fmt::Result {
+        self.write_separator()
+    }
+
+    fn begin_map(&mut self) -> fmt::Result {
+        self.begin('{')
+    }
+
+    fn key(&mut self, key: &dyn Display) -> fmt::Result {
+        self.write_separator()?;
+
+        self.f.write_fmt(format_args!("{key}"))
+    }
+
+    fn value(&mut self) -> fmt::Result {
+        self.f.write_str(": ")
+    }
+
+    fn end_list(&mut self) -> fmt::Result {
+        self.end(']')
+    }
+
+    fn end_map(&mut self) -> fmt::Result {
+        self.end('}')
+    }
+}
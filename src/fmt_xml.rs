@@ -0,0 +1,356 @@
+use crate::utilities::{self, IndentWriter};
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+const INDENT: &str = "    ";
+
+fn write_escaped(args: Arguments, escape_quotes: bool, f: &mut Formatter) -> fmt::Result {
+    utilities::try_for_each_char(args, |c| match c {
+        '&' => f.write_str("&amp;"),
+        '<' => f.write_str("&lt;"),
+        '>' => f.write_str("&gt;"),
+        '"' if escape_quotes => f.write_str("&quot;"),
+        '\'' if escape_quotes => f.write_str("&apos;"),
+        c => f.write_char(c),
+    })
+}
+
+const fn is_name_start_char(c: char) -> bool {
+    matches!(
+        c,
+        ':' | 'A'..='Z'
+            | '_'
+            | 'a'..='z'
+            | '\u{c0}'..='\u{d6}'
+            | '\u{d8}'..='\u{f6}'
+            | '\u{f8}'..='\u{2ff}'
+            | '\u{370}'..='\u{37d}'
+            | '\u{37f}'..='\u{1fff}'
+            | '\u{200c}'..='\u{200d}'
+            | '\u{2070}'..='\u{218f}'
+            | '\u{2c00}'..='\u{2fef}'
+            | '\u{3001}'..='\u{d7ff}'
+            | '\u{f900}'..='\u{fdcf}'
+            | '\u{fdf0}'..='\u{fffd}'
+            | '\u{10000}'..='\u{effff}'
+    )
+}
+
+const fn is_name_char(c: char) -> bool {
+    is_name_start_char(c)
+        || matches!(c, '-' | '.' | '0'..='9' | '\u{b7}' | '\u{300}'..='\u{36f}' | '\u{203f}'..='\u{2040}')
+}
+
+fn write_name(args: Arguments, f: &mut Formatter) -> fmt::Result {
+    // Names can not be escaped, so characters that are not allowed in XML names are replaced with `_`.
+    let mut is_first = true;
+
+    utilities::try_for_each_char(args, |c| {
+        let is_valid = if is_first {
+            is_name_start_char(c)
+        } else {
+            is_name_char(c)
+        };
+
+        is_first = false;
+
+        f.write_char(if is_valid { c } else { '_' })
+    })?;
+
+    if is_first {
+        f.write_char('_')
+    } else {
+        Ok(())
+    }
+}
+
+/// [`Debug`] or [`Display`] a [`Display`] object as XML text, with `&`, `<` and `>` escaped.
+pub struct FmtXmlText<T>
+where
+    T: ?Sized,
+{
+    value: T,
+}
+
+impl<T> FmtXmlText<T> {
+    const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> Debug for FmtXmlText<T>
+where
+    T: Display + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_escaped(format_args!("{}", &&self.value), false, f)
+    }
+}
+
+impl<T> Display for FmtXmlText<T>
+where
+    T: Display + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// [`Debug`] or [`Display`] an XML element with attributes and children.
+///
+/// If the alternate flag is specified in the format string, each child is written on its own line, indented by four
+/// spaces, and the alternate flag is passed on to the children.
+pub struct FmtXmlElement<N, A, C>
+where
+    C: ?Sized,
+{
+    name: N,
+    attributes_fn: A,
+    children_fn: C,
+}
+
+impl<N, A, C> FmtXmlElement<N, A, C> {
+    const fn new(name: N, attributes_fn: A, children_fn: C) -> Self {
+        Self {
+            name,
+            attributes_fn,
+            children_fn,
+        }
+    }
+}
+
+impl<N, A, C, IA, K, V, IC> Debug for FmtXmlElement<N, A, C>
+where
+    N: Display,
+    A: Fn() -> IA,
+    IA: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+    C: Fn() -> IC + ?Sized,
+    IC: IntoIterator,
+    IC::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_char('<')?;
+        write_name(format_args!("{}", self.name), f)?;
+
+        for (key, value) in (self.attributes_fn)() {
+            f.write_char(' ')?;
+            write_name(format_args!("{key}"), f)?;
+            f.write_str("=\"")?;
+            write_escaped(format_args!("{value}"), true, f)?;
+            f.write_char('"')?;
+        }
+
+        let mut children = (self.children_fn)().into_iter();
+
+        if let Some(first) = children.next() {
+            f.write_char('>')?;
+
+            if f.alternate() {
+                let mut writer = IndentWriter::new(f, INDENT);
+
+                for child in Some(first).into_iter().chain(children) {
+                    writer.write_fmt(format_args!("\n{child:#}"))?;
+                }
+
+                f.write_char('\n')?;
+            } else {
+                f.write_fmt(format_args!("{first}"))?;
+
+                for child in children {
+                    f.write_fmt(format_args!("{child}"))?;
+                }
+            }
+
+            f.write_str("</")?;
+            write_name(format_args!("{}", self.name), f)?;
+            f.write_char('>')
+        } else {
+            f.write_str("/>")
+        }
+    }
+}
+
+impl<N, A, C, IA, K, V, IC> Display for FmtXmlElement<N, A, C>
+where
+    N: Display,
+    A: Fn() -> IA,
+    IA: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+    C: Fn() -> IC + ?Sized,
+    IC: IntoIterator,
+    IC::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Display`] object as XML text, with `&`, `<` and `>` escaped.
+///
+/// Example:
+///
+/// ```rust
+/// let fmt = fmt_tools::fmt_xml_text("a < b && c");
+///
+/// assert_eq!(format!("{fmt:?}"), "a &lt; b &amp;&amp; c");
+/// assert_eq!(format!("{fmt}"), "a &lt; b &amp;&amp; c");
+/// ```
+pub const fn fmt_xml_text<T>(value: T) -> FmtXmlText<T>
+where
+    T: Display,
+{
+    FmtXmlText::new(value)
+}
+
+/// Creates an object that [`Debug`] or [`Display`] an XML element with attributes provided by `attributes_fn` and
+/// children provided by `children_fn`. Attribute values are escaped, while children are written as is, so text
+/// children should be wrapped with [`fmt_xml_text`]. Characters that are not allowed in XML names are replaced with `_`
+/// in the element name and attribute keys, and empty names are written as `_`. An element without children is written
+/// as a self-closing tag.
+///
+/// If the alternate flag is specified in the format string, each child is written on its own line, indented by four
+/// spaces, and the alternate flag is passed on to the children.
+///
+/// Example:
+///
+/// ```rust
+/// let item = |text| {
+///     fmt_tools::fmt_xml_element("li", || [("title", "\"quoted\"")], move || [fmt_tools::fmt_xml_text(text)])
+/// };
+/// let fmt = fmt_tools::fmt_xml_element("ul", || [("id", "list")], || [item("a & b"), item("c")]);
+///
+/// assert_eq!(
+///     format!("{fmt}"),
+///     r#"<ul id="list"><li title="&quot;quoted&quot;">a &amp; b</li><li title="&quot;quoted&quot;">c</li></ul>"#,
+/// );
+///
+/// assert_eq!(
+///     format!("{fmt:#}"),
+///     r#"<ul id="list">
+///     <li title="&quot;quoted&quot;">
+///         a &amp; b
+///     </li>
+///     <li title="&quot;quoted&quot;">
+///         c
+///     </li>
+/// </ul>"#,
+/// );
+///
+/// let empty = fmt_tools::fmt_xml_element("br", || [("class", "x")], || [""; 0]);
+///
+/// assert_eq!(format!("{empty:?}"), r#"<br class="x"/>"#);
+/// ```
+pub const fn fmt_xml_element<N, A, C, IA, K, V, IC>(name: N, attributes_fn: A, children_fn: C) -> FmtXmlElement<N, A, C>
+where
+    N: Display,
+    A: Fn() -> IA,
+    IA: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+    C: Fn() -> IC,
+    IC: IntoIterator,
+    IC::Item: Display,
+{
+    FmtXmlElement::new(name, attributes_fn, children_fn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FmtXmlElement, FmtXmlText};
+    use core::fmt::Display;
+
+    #[test]
+    fn test_fmt_xml_text() {
+        let test_cases = [
+            ("", ""),
+            ("abc", "abc"),
+            ("<a href=\"x\">&'", "&lt;a href=\"x\"&gt;&amp;'"),
+        ];
+
+        for (value, expected) in test_cases {
+            let fmt = super::fmt_xml_text(value);
+            let unsized_fmt: &FmtXmlText<dyn Display> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_fmt_xml_element() {
+        #[allow(trivial_casts)]
+        let test_cases = [
+            (&[] as &[(&str, &str)], &[] as &[&str], "<a/>", "<a/>"),
+            (
+                &[("x", "1"), ("y", "<'\">")],
+                &[],
+                r#"<a x="1" y="&lt;&apos;&quot;&gt;"/>"#,
+                r#"<a x="1" y="&lt;&apos;&quot;&gt;"/>"#,
+            ),
+            (&[], &["b"], "<a>b</a>", "<a>\n    b\n</a>"),
+            (
+                &[("x", "1")],
+                &["b", "c"],
+                r#"<a x="1">bc</a>"#,
+                "<a x=\"1\">\n    b\n    c\n</a>",
+            ),
+            (&[], &["b\nc", ""], "<a>b\nc</a>", "<a>\n    b\n    c\n\n</a>"),
+        ];
+
+        for (attributes, children, expected, expected_alternate) in test_cases {
+            let fmt = super::fmt_xml_element("a", || attributes.iter().copied(), || children);
+            let unsized_fmt: &FmtXmlElement<_, _, dyn Fn() -> _> = &fmt;
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt:?}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+            assert_eq!(std::format!("{fmt:#?}"), expected_alternate);
+            assert_eq!(std::format!("{fmt:#}"), expected_alternate);
+        }
+    }
+
+    #[test]
+    fn test_fmt_xml_element_names() {
+        let test_cases = [
+            ("a", "k", r#"<a k="v">c</a>"#),
+            ("a:b-c.d_1", "x:y", r#"<a:b-c.d_1 x:y="v">c</a:b-c.d_1>"#),
+            ("日本", "é", r#"<日本 é="v">c</日本>"#),
+            ("", "", r#"<_ _="v">c</_>"#),
+            ("1a", "-k", r#"<_a _k="v">c</_a>"#),
+            (
+                "a><script",
+                "a=\"x\" onload",
+                r#"<a__script a__x__onload="v">c</a__script>"#,
+            ),
+            ("a/", "k/>", r#"<a_ k__="v">c</a_>"#),
+        ];
+
+        for (name, key, expected) in test_cases {
+            let fmt = super::fmt_xml_element(name, || [(key, "v")], || ["c"]);
+
+            assert_eq!(std::format!("{fmt}"), expected);
+        }
+
+        let empty = super::fmt_xml_element("a b", || [("k", "v"); 0], || [""; 0]);
+
+        assert_eq!(std::format!("{empty}"), "<a_b/>");
+    }
+
+    #[test]
+    fn test_fmt_xml_element_nested() {
+        let leaf = || super::fmt_xml_element("c", || [("k", "v")], || [""; 0]);
+        let middle = || super::fmt_xml_element("b", || [("k", "v"); 0], move || [leaf(), leaf()]);
+        let fmt = super::fmt_xml_element("a", || [("k", "v"); 0], move || [middle()]);
+
+        assert_eq!(std::format!("{fmt}"), r#"<a><b><c k="v"/><c k="v"/></b></a>"#);
+        assert_eq!(
+            std::format!("{fmt:#}"),
+            "<a>\n    <b>\n        <c k=\"v\"/>\n        <c k=\"v\"/>\n    </b>\n</a>"
+        );
+    }
+}
@@ -0,0 +1,346 @@
+use crate::fmt_visitor::FmtVisitor;
+use crate::node::{Node, Scalar};
+use crate::utilities;
+use core::fmt::{self, Arguments, Debug, Display, Formatter, Write};
+
+/// Values that would be parsed as booleans or nulls if not quoted.
+const RESERVED_WORDS: [&str; 10] = ["false", "n", "no", "null", "off", "on", "true", "y", "yes", "~"];
+
+fn needs_quoting(args: Arguments) -> Result<bool, fmt::Error> {
+    let mut length = 0;
+    let mut word = [0_u8; 5];
+    let mut previous = None;
+    let mut needs_quoting = false;
+
+    utilities::try_for_each_char(args, |c| {
+        needs_quoting |= c.is_control()
+            || match previous {
+                None => c.is_whitespace() || c.is_ascii_digit() || "-?:,[]{}#&*!|>'\"%@`.+".contains(c),
+                Some(previous) => (previous == ':' && c == ' ') || (previous == ' ' && c == '#'),
+            };
+
+        if let Some(byte) = word.get_mut(length) {
+            *byte = if c.is_ascii() {
+                (c as u8).to_ascii_lowercase()
+            } else {
+                0xff
+            };
+        }
+
+        length += 1;
+        previous = Some(c);
+
+        Ok(())
+    })?;
+
+    let is_reserved_word = word
+        .get(..length)
+        .and_then(|word| core::str::from_utf8(word).ok())
+        .is_some_and(|word| RESERVED_WORDS.contains(&word));
+
+    Ok(needs_quoting || is_reserved_word || previous.is_none_or(|c| c == ':' || c.is_whitespace()))
+}
+
+fn write_string(args: Arguments, f: &mut Formatter) -> fmt::Result {
+    if needs_quoting(args)? {
+        f.write_char('"')?;
+
+        utilities::try_for_each_char(args, |c| match c {
+            '"' => f.write_str("\\\""),
+            '\\' => f.write_str("\\\\"),
+            '\n' => f.write_str("\\n"),
+            '\r' => f.write_str("\\r"),
+            '\t' => f.write_str("\\t"),
+            c if c.is_control() => f.write_fmt(format_args!("\\u{:04X}", u32::from(c))),
+            c => f.write_char(c),
+        })?;
+
+        f.write_char('"')
+    } else {
+        f.write_fmt(args)
+    }
+}
+
+/// A [`FmtVisitor`] that writes values as a block style YAML document. Strings are quoted only if they would not be
+/// parsed back as the same string otherwise. Nested collections are indented by two spaces.
+pub struct YamlVisitor<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    depth: usize,
+    has_entries: bool,
+    after_key: bool,
+}
+
+impl<'a, 'b> YamlVisitor<'a, 'b> {
+    /// Creates a new `YamlVisitor` that writes to `f`.
+    pub fn new(f: &'a mut Formatter<'b>) -> Self {
+        Self {
+            f,
+            depth: 0,
+            has_entries: false,
+            after_key: false,
+        }
+    }
+
+    fn begin(&mut self) {
+        self.depth += 1;
+        self.has_entries = false;
+    }
+
+    /// Moves to the position of a new list item or map entry. The first entry of a collection nested in a list item
+    /// continues the line of the item, the other ones start a new line. A collection that is a map value is still
+    /// preceded by its key, which is what `after_key` records until the first entry.
+    fn write_separator(&mut self) -> fmt::Result {
+        if self.has_entries || self.after_key {
+            self.f.write_char('\n')?;
+
+            (1..self.depth).try_for_each(|_| self.f.write_str("  "))?;
+        }
+
+        self.has_entries = true;
+        self.after_key = false;
+
+        Ok(())
+    }
+
+    /// Ends the current collection, writing it as `empty` if it has no entries.
+    fn end(&mut self, empty: &str) -> fmt::Result {
+        self.depth = self.depth.saturating_sub(1);
+
+        if !self.has_entries {
+            if self.after_key {
+                self.f.write_char(' ')?;
+            }
+
+            self.f.write_str(empty)?;
+        }
+
+        // The parent collection, if any, has at least one entry, which is the one that just ended.
+        self.has_entries = true;
+        self.after_key = false;
+
+        Ok(())
+    }
+}
+
+impl FmtVisitor for YamlVisitor<'_, '_> {
+    fn scalar(&mut self, scalar: Scalar) -> fmt::Result {
+        if self.after_key {
+            self.after_key = false;
+            self.f.write_char(' ')?;
+        }
+
+        match scalar {
+            Scalar::Null => self.f.write_str("null"),
+            Scalar::Bool(value) => self.f.write_str(if value { "true" } else { "false" }),
+            Scalar::Integer(value) => self.f.write_fmt(format_args!("{value}")),
+            Scalar::Float(value) => {
+                if value.is_nan() {
+                    self.f.write_str(".nan")
+                } else if value.is_infinite() {
+                    self.f
+                        .write_str(if value.is_sign_negative() { "-.inf" } else { ".inf" })
+                } else {
+                    self.f.write_fmt(format_args!("{value:?}"))
+                }
+            }
+            Scalar::String(value) => write_string(format_args!("{value}"), self.f),
+        }
+    }
+
+    fn begin_list(&mut self) -> fmt::Result {
+        self.begin();
+
+        Ok(())
+    }
+
+    fn entry(&mut self) -> fmt::Result {
+        self.write_separator()?;
+
+        self.f.write_str("- ")
+    }
+
+    fn begin_map(&mut self) -> fmt::Result {
+        self.begin();
+
+        Ok(())
+    }
+
+    fn key(&mut self, key: &dyn Display) -> fmt::Result {
+        self.write_separator()?;
+
+        write_string(format_args!("{key}"), self.f)
+    }
+
+    fn value(&mut self) -> fmt::Result {
+        self.after_key = true;
+
+        self.f.write_char(':')
+    }
+
+    fn end_list(&mut self) -> fmt::Result {
+        self.end("[]")
+    }
+
+    fn end_map(&mut self) -> fmt::Result {
+        self.end("{}")
+    }
+}
+
+/// [`Debug`] or [`Display`] a [`Node`] as a block style YAML document.
+pub struct FmtYaml<T>
+where
+    T: ?Sized,
+{
+    value: T,
+}
+
+impl<T> FmtYaml<T> {
+    const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> Debug for FmtYaml<T>
+where
+    T: Node + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.visit(&mut YamlVisitor::new(f))
+    }
+}
+
+impl<T> Display for FmtYaml<T>
+where
+    T: Node + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Creates an object that [`Debug`] or [`Display`] a [`Node`] as a block style YAML document. Strings are quoted only
+/// if they would not be parsed back as the same string otherwise. Nested collections are indented by two spaces.
+///
+/// Example:
+///
+/// ```rust
+/// use fmt_tools::Node;
+///
+/// let database = fmt_tools::fmt_debug_map(|| [("host", "db: primary"), ("user", "admin")]);
+///
+/// let fmt = fmt_tools::fmt_yaml(fmt_tools::fmt_debug_map(|| {
+///     [
+///         ("name", &"app" as &dyn Node),
+///         ("debug", &false),
+///         ("ports", &[80, 443]),
+///         ("database", &database),
+///     ]
+/// }));
+///
+/// let expected = "\
+/// name: app
+/// debug: false
+/// ports:
+///   - 80
+///   - 443
+/// database:
+///   host: \"db: primary\"
+///   user: admin";
+///
+/// assert_eq!(format!("{fmt:?}"), expected);
+/// assert_eq!(format!("{fmt}"), expected);
+/// ```
+pub const fn fmt_yaml<T>(value: T) -> FmtYaml<T>
+where
+    T: Node,
+{
+    FmtYaml::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtYaml;
+    use crate::node::Node;
+
+    #[test]
+    fn test_fmt_yaml_scalars() {
+        let test_cases: [(&dyn Node, &str); 18] = [
+            (&None::<u32>, "null"),
+            (&true, "true"),
+            (&-3, "-3"),
+            (&1.0, "1.0"),
+            (&0.1_f32, "0.1"),
+            (&f64::NAN, ".nan"),
+            (&f64::NEG_INFINITY, "-.inf"),
+            (&'x', "x"),
+            (&"hello world", "hello world"),
+            (&"", "\"\""),
+            (&"yes", "\"yes\""),
+            (&"Null", "\"Null\""),
+            (&"123", "\"123\""),
+            (&"- a", "\"- a\""),
+            (&"a: b", "\"a: b\""),
+            (&"a #b", "\"a #b\""),
+            (&"a:b", "a:b"),
+            (&"say \"hi\"\n", "\"say \\\"hi\\\"\\n\""),
+        ];
+
+        for (value, expected) in test_cases {
+            let fmt = super::fmt_yaml(value);
+            let unsized_fmt: &FmtYaml<dyn Node> = &super::fmt_yaml(value);
+
+            assert_eq!(std::format!("{fmt:?}"), expected);
+            assert_eq!(std::format!("{fmt}"), expected);
+            assert_eq!(std::format!("{unsized_fmt}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_fmt_yaml_collections() {
+        let empty_list: [u32; 0] = [];
+        let empty_map = crate::fmt_debug_map(|| [(1, 2); 0]);
+        let inner_map = crate::fmt_debug_map(|| [("a", 1), ("b", 2)]);
+        let list_of_maps = [&inner_map, &inner_map];
+        let list_of_lists = [[1, 2], [3, 4]];
+
+        let test_cases: [(&dyn Node, &str); 6] = [
+            (&empty_list, "[]"),
+            (&empty_map, "{}"),
+            (&[1, 2], "- 1\n- 2"),
+            (&list_of_maps, "- a: 1\n  b: 2\n- a: 1\n  b: 2"),
+            (&list_of_lists, "- - 1\n  - 2\n- - 3\n  - 4"),
+            (&inner_map, "a: 1\nb: 2"),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(std::format!("{}", super::fmt_yaml(value)), expected);
+        }
+
+        #[allow(trivial_casts)]
+        let nested = crate::fmt_debug_map(|| {
+            [
+                ("empty list", &empty_list as &dyn Node),
+                ("empty map", &empty_map),
+                ("map", &inner_map),
+                ("list", &list_of_maps),
+                ("on", &Some("off")),
+            ]
+        });
+
+        assert_eq!(
+            std::format!("{}", super::fmt_yaml(nested)),
+            "\
+empty list: []
+empty map: {}
+map:
+  a: 1
+  b: 2
+list:
+  - a: 1
+    b: 2
+  - a: 1
+    b: 2
+\"on\": \"off\""
+        );
+    }
+}
@@ -0,0 +1,42 @@
+/// Like [`format_args_owned`](`crate::format_args_owned`), but evaluates explicit arguments immediately, so each
+/// argument decides how it is captured: `&value` captures by reference, while `value` captures by value.
+///
+/// Variables that are captured implicitly by the format string are captured by value.
+///
+/// Example:
+///
+/// ```rust
+/// let borrowed = vec![2];
+/// let owned = Box::new('A');
+/// let fmt = fmt_tools::format_args_capture!("{:?}, {}, {named}", &borrowed, borrowed.len(), named = owned);
+///
+/// assert_eq!(format!("{fmt:?}"), "[2], 1, A");
+/// assert_eq!(format!("{fmt}"), "[2], 1, A");
+///
+/// drop(fmt);
+/// drop(borrowed);
+/// ```
+#[macro_export]
+macro_rules! format_args_capture {
+    (@bind [$($format:tt)*] [$($bound:tt)*]) => {
+        $crate::fmt_fn(
+            move |__fmt_tools_formatter| ::core::fmt::Display::fmt(
+                &::core::format_args!($($format)*, $($bound)*),
+                __fmt_tools_formatter,
+            )
+        )
+    };
+    (@bind [$($format:tt)*] [$($bound:tt)*] $name:ident = $value:expr $(, $($rest:tt)*)?) => {{
+        let __fmt_tools_value = $value;
+
+        $crate::format_args_capture!(@bind [$($format)*] [$($bound)* $name = __fmt_tools_value,] $($($rest)*)?)
+    }};
+    (@bind [$($format:tt)*] [$($bound:tt)*] $value:expr $(, $($rest:tt)*)?) => {{
+        let __fmt_tools_value = $value;
+
+        $crate::format_args_capture!(@bind [$($format)*] [$($bound)* __fmt_tools_value,] $($($rest)*)?)
+    }};
+    ($format:literal $(, $($args:tt)*)?) => {
+        $crate::format_args_capture!(@bind [$format] [] $($($args)*)?)
+    };
+}
@@ -1,5 +1,7 @@
 /// Like [`format_args`](`::core::format_args`), but takes ownership of its arguments.
 ///
+/// The type of the returned object can not be named, use [`fmt_args`](`crate::fmt_args!`) if that is needed.
+///
 /// Example:
 ///
 /// ```rust
@@ -5,7 +5,6 @@
     missing_abi,
     missing_docs,
     noop_method_call,
-    pointer_structural_match,
     single_use_lifetimes,
     trivial_casts,
     trivial_numeric_casts,
@@ -44,7 +43,6 @@
     clippy::rc_mutex,
     clippy::rest_pat_in_fully_bound_structs,
     clippy::string_lit_as_bytes,
-    clippy::string_to_string,
     clippy::suboptimal_flops,
     clippy::suspicious_operation_groupings,
     clippy::todo,
@@ -64,24 +62,97 @@
 //! Provides various tools for creating objects that implement [`Debug`](`core::fmt::Debug`) and
 //! [`Display`](`core::fmt::Display`) traits.
 
+pub use self::fmt_args::{fmt_args, FmtArgs};
+pub use self::fmt_base_encoded::{fmt_base32, fmt_base64, fmt_base64_with, Base64Alphabet, FmtBase32, FmtBase64};
+pub use self::fmt_case::{
+    fmt_camel_case, fmt_case, fmt_kebab_case, fmt_pascal_case, fmt_screaming_snake_case, fmt_snake_case,
+    fmt_title_case, Case, FmtCase,
+};
+pub use self::fmt_csv::{fmt_csv, fmt_csv_row, fmt_csv_row_with, fmt_csv_with, FmtCsv, FmtCsvRow};
 pub use self::fmt_debug::{fmt_debug, FmtDebug};
 pub use self::fmt_display::{fmt_display, FmtDisplay};
+pub use self::fmt_error_chain::{fmt_error_chain, fmt_error_chain_with_max_depth, FmtErrorChain};
+pub use self::fmt_float::{fmt_float, fmt_float_f32, FloatStyle, FmtFloat};
 pub use self::fmt_fn::{fmt_fn, FmtFn};
-pub use self::fmt_list::{fmt_debug_list, fmt_display_list, FmtDebugList, FmtDisplayList};
-pub use self::fmt_map::{fmt_debug_map, fmt_display_map, FmtDebugMap, FmtDisplayMap};
+pub use self::fmt_join::{fmt_debug_join, fmt_display_join, DebugJoin, DisplayJoin, FmtDebugJoin, FmtDisplayJoin};
+pub use self::fmt_json::{fmt_json, FmtJson, JsonVisitor};
+pub use self::fmt_lazy::{fmt_lazy, FmtLazy};
+pub use self::fmt_list::{
+    fmt_debug_list, fmt_debug_list_with, fmt_display_list, FmtDebugList, FmtDebugListWith, FmtDisplayList,
+};
+pub use self::fmt_logfmt::{fmt_logfmt, FmtLogfmt};
+pub use self::fmt_map::{
+    fmt_debug_map, fmt_debug_map_with, fmt_display_map, FmtDebugMap, FmtDebugMapWith, FmtDisplayMap,
+};
+pub use self::fmt_percent::{fmt_percent, fmt_ratio, FmtPercent, FmtRatio};
+pub use self::fmt_percent_encoded::{fmt_percent_encoded, fmt_query, AsciiSet, FmtPercentEncoded, FmtQuery};
+pub use self::fmt_redacted::{fmt_redacted, fmt_redacted_with, FmtRedacted, RedactionPolicy};
+pub use self::fmt_repeat::{fmt_repeat, fmt_separated_repeat, FmtRepeat};
 pub use self::fmt_separated_list::{
-    fmt_separated_debug_list, fmt_separated_display_list, FmtSeparatedDebugList, FmtSeparatedDisplayList,
+    fmt_separated_debug_list, fmt_separated_display_list, fmt_separated_list_with, FmtSeparatedDebugList,
+    FmtSeparatedDisplayList, FmtSeparatedListWith,
+};
+pub use self::fmt_separated_map::{
+    fmt_separated_debug_map, fmt_separated_display_map, FmtSeparatedDebugMap, FmtSeparatedDisplayMap, MapSeparators,
 };
-pub use self::fmt_set::{fmt_debug_set, fmt_display_set, FmtDebugSet, FmtDisplaySet};
+#[cfg(feature = "serde")]
+pub use self::fmt_serialize_json::{fmt_serialize_json, FmtSerializeJson, SerializeJsonError};
+pub use self::fmt_set::{
+    fmt_debug_set, fmt_debug_set_with, fmt_display_set, FmtDebugSet, FmtDebugSetWith, FmtDisplaySet,
+};
+pub use self::fmt_toml::{fmt_toml, FmtToml};
+#[cfg(feature = "ufmt")]
+pub use self::fmt_ufmt_fn::{fmt_ufmt_fn, FmtUfmtFn, UfmtWriteError};
+pub use self::fmt_visitor::{DebugVisitor, FmtVisitor};
+pub use self::fmt_xml::{fmt_xml_element, fmt_xml_text, FmtXmlElement, FmtXmlText};
+pub use self::fmt_yaml::{fmt_yaml, FmtYaml, YamlVisitor};
+pub use self::node::{Node, NodeKind, Scalar};
+#[cfg(feature = "derive")]
+pub use fmt_tools_derive::{FmtDebug, FmtDisplay};
 
 #[cfg(test)]
 extern crate std;
 
+#[cfg(all(test, not(feature = "defmt")))]
+use defmt as _;
+#[cfg(all(test, not(feature = "serde")))]
+use serde as _;
+#[cfg(test)]
+use serde_json as _;
+#[cfg(all(test, not(feature = "ufmt")))]
+use ufmt as _;
+
+mod fmt_args;
+mod fmt_base_encoded;
+mod fmt_case;
+mod fmt_csv;
 mod fmt_debug;
 mod fmt_display;
+mod fmt_error_chain;
+mod fmt_float;
 mod fmt_fn;
+mod fmt_join;
+mod fmt_json;
+mod fmt_lazy;
 mod fmt_list;
+mod fmt_logfmt;
 mod fmt_map;
+mod fmt_percent;
+mod fmt_percent_encoded;
+mod fmt_redacted;
+mod fmt_repeat;
 mod fmt_separated_list;
+mod fmt_separated_map;
+#[cfg(feature = "serde")]
+mod fmt_serialize_json;
 mod fmt_set;
+mod fmt_toml;
+#[cfg(feature = "ufmt")]
+mod fmt_ufmt_fn;
+mod fmt_visitor;
+mod fmt_xml;
+mod fmt_yaml;
+mod format_args_capture;
 mod format_args_owned;
+mod node;
+mod utilities;
@@ -0,0 +1,249 @@
+use crate::fmt_visitor::{DebugVisitor, FmtVisitor};
+use crate::utilities;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A scalar value of a [`Node`].
+#[derive(Clone, Copy)]
+pub enum Scalar<'a> {
+    /// A missing value.
+    Null,
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value, written as is.
+    Integer(&'a dyn Display),
+    /// A floating point value.
+    Float(f64),
+    /// A string value, quoted and escaped as needed.
+    String(&'a dyn Display),
+}
+
+/// The kind of a [`Node`].
+#[derive(Clone, Copy)]
+pub enum NodeKind<'a> {
+    /// A scalar value.
+    Scalar(Scalar<'a>),
+    /// A list of nodes, provided by [`Node::for_each_item`].
+    List,
+    /// A map from [`Display`] keys to nodes, provided by [`Node::for_each_entry`].
+    Map,
+}
+
+/// A value that can be rendered by structured renderers like [`fmt_json`](`crate::fmt_json`),
+/// [`fmt_yaml`](`crate::fmt_yaml`) and [`fmt_toml`](`crate::fmt_toml`), or described to any
+/// [`FmtVisitor`](`crate::FmtVisitor`) with [`Node::visit`].
+///
+/// Renderers may iterate the items or entries of a node more than once.
+pub trait Node {
+    /// Returns the kind of the node.
+    fn kind(&self) -> NodeKind<'_>;
+
+    /// Calls `item_fn` on each item if the node is a list.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `item_fn`.
+    fn for_each_item(&self, _item_fn: &mut dyn FnMut(&dyn Node) -> fmt::Result) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Calls `entry_fn` on each entry if the node is a map.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `entry_fn`.
+    fn for_each_entry(&self, _entry_fn: &mut dyn FnMut(&dyn Display, &dyn Node) -> fmt::Result) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Describes the node to `visitor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `visitor`.
+    fn visit(&self, visitor: &mut dyn FmtVisitor) -> fmt::Result {
+        match self.kind() {
+            NodeKind::Scalar(scalar) => visitor.scalar(scalar),
+            NodeKind::List => {
+                visitor.begin_list()?;
+
+                self.for_each_item(&mut |item| {
+                    visitor.entry()?;
+
+                    item.visit(visitor)
+                })?;
+
+                visitor.end_list()
+            }
+            NodeKind::Map => {
+                visitor.begin_map()?;
+
+                self.for_each_entry(&mut |key, value| {
+                    visitor.key(key)?;
+                    visitor.value()?;
+
+                    value.visit(visitor)
+                })?;
+
+                visitor.end_map()
+            }
+        }
+    }
+}
+
+impl Debug for dyn Node + '_ {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.visit(&mut DebugVisitor::new(f))
+    }
+}
+
+impl<T> Node for &T
+where
+    T: Node + ?Sized,
+{
+    fn kind(&self) -> NodeKind<'_> {
+        (**self).kind()
+    }
+
+    fn for_each_item(&self, item_fn: &mut dyn FnMut(&dyn Node) -> fmt::Result) -> fmt::Result {
+        (**self).for_each_item(item_fn)
+    }
+
+    fn for_each_entry(&self, entry_fn: &mut dyn FnMut(&dyn Display, &dyn Node) -> fmt::Result) -> fmt::Result {
+        (**self).for_each_entry(entry_fn)
+    }
+
+    fn visit(&self, visitor: &mut dyn FmtVisitor) -> fmt::Result {
+        (**self).visit(visitor)
+    }
+}
+
+impl<T> Node for Option<T>
+where
+    T: Node,
+{
+    fn kind(&self) -> NodeKind<'_> {
+        self.as_ref().map_or(NodeKind::Scalar(Scalar::Null), T::kind)
+    }
+
+    fn for_each_item(&self, item_fn: &mut dyn FnMut(&dyn Node) -> fmt::Result) -> fmt::Result {
+        self.as_ref().map_or(Ok(()), |value| value.for_each_item(item_fn))
+    }
+
+    fn for_each_entry(&self, entry_fn: &mut dyn FnMut(&dyn Display, &dyn Node) -> fmt::Result) -> fmt::Result {
+        self.as_ref().map_or(Ok(()), |value| value.for_each_entry(entry_fn))
+    }
+}
+
+impl<T> Node for [T]
+where
+    T: Node,
+{
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::List
+    }
+
+    fn for_each_item(&self, item_fn: &mut dyn FnMut(&dyn Node) -> fmt::Result) -> fmt::Result {
+        self.iter().try_for_each(|item| item_fn(item))
+    }
+}
+
+impl<T, const N: usize> Node for [T; N]
+where
+    T: Node,
+{
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::List
+    }
+
+    fn for_each_item(&self, item_fn: &mut dyn FnMut(&dyn Node) -> fmt::Result) -> fmt::Result {
+        self.as_slice().for_each_item(item_fn)
+    }
+}
+
+impl Node for bool {
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::Scalar(Scalar::Bool(*self))
+    }
+}
+
+macro_rules! impl_node_for_integers {
+    ($($type:ty)*) => {
+        $(
+            impl Node for $type {
+                fn kind(&self) -> NodeKind<'_> {
+                    NodeKind::Scalar(Scalar::Integer(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_node_for_integers!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl Node for f32 {
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::Scalar(Scalar::Float(utilities::f32_to_shortest_f64(*self)))
+    }
+}
+
+impl Node for f64 {
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::Scalar(Scalar::Float(*self))
+    }
+}
+
+impl Node for char {
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::Scalar(Scalar::String(self))
+    }
+}
+
+impl Node for &str {
+    fn kind(&self) -> NodeKind<'_> {
+        NodeKind::Scalar(Scalar::String(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Node;
+
+    #[test]
+    fn test_node_debug() {
+        let map = crate::fmt_debug_map(|| [("a", 1), ("b", 2)]);
+
+        #[allow(trivial_casts)]
+        let test_cases: [(&dyn Node, &str); 10] = [
+            (&None::<u32>, "None"),
+            (&Some(true), "true"),
+            (&7_u8, "7"),
+            (&1.0_f32, "1.0"),
+            (&0.1_f32, "0.1"),
+            (&'"', r#""\"""#),
+            (&"a\nb", r#""a\nb""#),
+            (&[1, 2], "[1, 2]"),
+            (&map, "{a: 1, b: 2}"),
+            (&[&map as &dyn Node, &[0_u32; 0]], "[{a: 1, b: 2}, []]"),
+        ];
+
+        for (node, expected) in test_cases {
+            assert_eq!(std::format!("{node:?}"), expected);
+        }
+    }
+
+    #[test]
+    fn test_node_debug_alternate() {
+        let map = crate::fmt_debug_map(|| [("a", &[1, 2][..]), ("b", &[])]);
+        let map: &dyn Node = &map;
+        let list: &dyn Node = &[map, map];
+
+        assert_eq!(
+            std::format!("{map:#?}"),
+            "{\n    a: [\n        1,\n        2,\n    ],\n    b: [],\n}"
+        );
+        assert_eq!(
+            std::format!("{list:#?}"),
+            "[\n    {\n        a: [\n            1,\n            2,\n        ],\n        b: [],\n    },\n    {\n        a: [\n            1,\n            2,\n        ],\n        b: [],\n    },\n]"
+        );
+    }
+}
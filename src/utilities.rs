@@ -0,0 +1,128 @@
+use core::fmt::{self, Arguments, Write};
+
+struct CharWriter<F> {
+    char_fn: F,
+}
+
+impl<F> Write for CharWriter<F>
+where
+    F: FnMut(char) -> fmt::Result,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(&mut self.char_fn)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        (self.char_fn)(c)
+    }
+}
+
+/// Formats `args` and feeds each character of the output to `char_fn`, without allocating.
+pub(crate) fn try_for_each_char(args: Arguments, char_fn: impl FnMut(char) -> fmt::Result) -> fmt::Result {
+    fmt::write(&mut CharWriter { char_fn }, args)
+}
+
+/// A [`Write`] adapter that indents every non-empty line written through it.
+pub(crate) struct IndentWriter<'a, W>
+where
+    W: ?Sized,
+{
+    indent: &'a str,
+    on_newline: bool,
+    writer: &'a mut W,
+}
+
+impl<'a, W> IndentWriter<'a, W>
+where
+    W: Write + ?Sized,
+{
+    pub(crate) fn new(writer: &'a mut W, indent: &'a str) -> Self {
+        Self {
+            indent,
+            on_newline: true,
+            writer,
+        }
+    }
+}
+
+impl<W> Write for IndentWriter<'_, W>
+where
+    W: Write + ?Sized,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if self.on_newline && line != "\n" {
+                self.writer.write_str(self.indent)?;
+            }
+
+            self.on_newline = line.ends_with('\n');
+            self.writer.write_str(line)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A fixed capacity buffer for formatting short strings without allocation. A write that does not fit fails as a whole,
+/// so the content is always valid UTF-8.
+pub(crate) struct StackBuffer<const N: usize> {
+    bytes: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> StackBuffer<N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            length: 0,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> Write for StackBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let target = self
+            .bytes
+            .get_mut(self.length..self.length + s.len())
+            .ok_or(fmt::Error)?;
+
+        target.copy_from_slice(s.as_bytes());
+        self.length += s.len();
+
+        Ok(())
+    }
+}
+
+/// Converts an `f32` to the `f64` that has the same shortest representation, so that `0.1_f32` is written as `0.1`
+/// rather than as `0.10000000149011612`, which is what converting it directly would produce.
+pub(crate) fn f32_to_shortest_f64(value: f32) -> f64 {
+    let mut buffer = StackBuffer::<32>::new();
+
+    if buffer.write_fmt(format_args!("{value:?}")).is_ok() {
+        if let Ok(result) = buffer.as_str().parse() {
+            return result;
+        }
+    }
+
+    f64::from(value)
+}
+
+/// Encodes `value` with the mocked `defmt` logger of the `unstable-test` feature, returning the index of the first
+/// interned string along with the encoded bytes. The mocked logger interns strings by incrementing an index, so the
+/// bytes record the sequence of writes rather than the content of the format strings.
+#[cfg(all(test, feature = "defmt"))]
+pub(crate) fn defmt_encode<T>(value: &T) -> (u16, std::vec::Vec<u8>)
+where
+    T: defmt::Format + ?Sized,
+{
+    let index = defmt::export::fetch_string_index();
+
+    defmt::export::istr(&T::_format_tag());
+    value._format_data();
+
+    (index, defmt::export::fetch_bytes())
+}
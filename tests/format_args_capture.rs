@@ -0,0 +1,44 @@
+#[test]
+fn test_format_args_capture() {
+    let value_1 = vec![2];
+    let value_2 = String::from("B");
+
+    let fmt = {
+        let value_3 = Box::new('A');
+        let value_4 = Box::new(5);
+
+        fmt_tools::format_args_capture!(
+            "{:?}, {named}, {value_4}, {}, {}",
+            &value_1,
+            value_2.as_str(),
+            value_1.len(),
+            named = value_3,
+        )
+    };
+
+    assert_eq!(format!("{fmt:?}"), "[2], A, 5, B, 1");
+    assert_eq!(format!("{fmt}"), "[2], A, 5, B, 1");
+}
+
+#[test]
+fn test_format_args_capture_evaluates_arguments_once() {
+    let mut count = 0;
+    let fmt = fmt_tools::format_args_capture!("{}", {
+        count += 1;
+
+        count
+    });
+
+    assert_eq!(format!("{fmt}"), "1");
+    assert_eq!(format!("{fmt}"), "1");
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_format_args_capture_without_arguments() {
+    let value = 7;
+
+    assert_eq!(format!("{}", fmt_tools::format_args_capture!("foo")), "foo");
+    assert_eq!(format!("{}", fmt_tools::format_args_capture!("foo",)), "foo");
+    assert_eq!(format!("{}", fmt_tools::format_args_capture!("{value}")), "7");
+}